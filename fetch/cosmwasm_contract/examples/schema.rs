@@ -4,9 +4,15 @@ use std::fs::create_dir_all;
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
 use bridge::msg::{
-    CapResponse, DenomResponse, ExecuteMsg, InstantiateMsg, PausedSinceBlockResponse, QueryMsg,
-    RelayEonResponse, ReverseAggregatedAllowanceResponse, RoleResponse, SupplyResponse,
-    SwapMaxResponse,
+    AddressListResponse, AssetResponse, CapResponse, ClaimableResponse, CustodyModeResponse,
+    DenomResponse, DustPolicyResponse, ExecuteMsg, FullStateResponse, HeldPayoutsResponse,
+    InstantiateMsg, ListAssetsResponse, ListClaimableResponse, ListRoleMembersResponse,
+    ListSwapsResponse, OutflowCapacityResponse, PauseStatusResponse, PausedSinceBlockResponse,
+    PayoutModeResponse, PendingAdminResponse, PendingAttestationsResponse,
+    PendingOperationsResponse, QueryMsg, ReceiveMsg, RefundResponse, RelayEonResponse,
+    ReverseAggregatedAllowanceResponse, ReverseSwapIdSealResponse, ReverseSwapProcessedResponse,
+    RoleResponse, RolesOfResponse, SenderUsageResponse, SupplyResponse, SwapMaxResponse,
+    SwapResponse, TokenfactoryDialectResponse,
 };
 
 fn main() {
@@ -18,12 +24,37 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(ReceiveMsg), &out_dir);
     export_schema(&schema_for!(RoleResponse), &out_dir);
     export_schema(&schema_for!(RelayEonResponse), &out_dir);
     export_schema(&schema_for!(SupplyResponse), &out_dir);
     export_schema(&schema_for!(PausedSinceBlockResponse), &out_dir);
     export_schema(&schema_for!(DenomResponse), &out_dir);
+    export_schema(&schema_for!(FullStateResponse), &out_dir);
     export_schema(&schema_for!(CapResponse), &out_dir);
     export_schema(&schema_for!(SwapMaxResponse), &out_dir);
     export_schema(&schema_for!(ReverseAggregatedAllowanceResponse), &out_dir);
+    export_schema(&schema_for!(ReverseSwapProcessedResponse), &out_dir);
+    export_schema(&schema_for!(ReverseSwapIdSealResponse), &out_dir);
+    export_schema(&schema_for!(SwapResponse), &out_dir);
+    export_schema(&schema_for!(ListSwapsResponse), &out_dir);
+    export_schema(&schema_for!(RefundResponse), &out_dir);
+    export_schema(&schema_for!(ListRoleMembersResponse), &out_dir);
+    export_schema(&schema_for!(RolesOfResponse), &out_dir);
+    export_schema(&schema_for!(PendingAdminResponse), &out_dir);
+    export_schema(&schema_for!(PendingOperationsResponse), &out_dir);
+    export_schema(&schema_for!(PendingAttestationsResponse), &out_dir);
+    export_schema(&schema_for!(OutflowCapacityResponse), &out_dir);
+    export_schema(&schema_for!(SenderUsageResponse), &out_dir);
+    export_schema(&schema_for!(DustPolicyResponse), &out_dir);
+    export_schema(&schema_for!(CustodyModeResponse), &out_dir);
+    export_schema(&schema_for!(PayoutModeResponse), &out_dir);
+    export_schema(&schema_for!(PauseStatusResponse), &out_dir);
+    export_schema(&schema_for!(ListClaimableResponse), &out_dir);
+    export_schema(&schema_for!(TokenfactoryDialectResponse), &out_dir);
+    export_schema(&schema_for!(ClaimableResponse), &out_dir);
+    export_schema(&schema_for!(AssetResponse), &out_dir);
+    export_schema(&schema_for!(ListAssetsResponse), &out_dir);
+    export_schema(&schema_for!(AddressListResponse), &out_dir);
+    export_schema(&schema_for!(HeldPayoutsResponse), &out_dir);
 }
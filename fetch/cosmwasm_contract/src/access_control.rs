@@ -1,9 +1,30 @@
-use crate::error::{ERR_ACCESS_CONTROL_ALREADY_HAS_ROLE, ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE};
-use cosmwasm_std::storage_keys::to_length_prefixed_nested;
-use cosmwasm_std::{Addr, StdError, StdResult, Storage};
+use crate::error::ContractError;
+use cosmwasm_std::storage_keys::{to_length_prefixed, to_length_prefixed_nested};
+use cosmwasm_std::{Addr, Order, StdError, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 pub static ACCESS_CONTROL_KEY: &[u8] = b"access_control";
+pub static ROLE_MEMBERS_KEY: &str = "role_members";
+
+/// Secondary index (role, addr) of the `access_control/<addr>/<ROLE>` keys
+pub const ROLE_MEMBERS: Map<(&str, &Addr), ()> = Map::new(ROLE_MEMBERS_KEY);
+
+pub static ADMIN_COUNT_KEY: &str = "admin_count";
+pub const ADMIN_COUNT: Item<u64> = Item::new(ADMIN_COUNT_KEY);
+
+pub static PENDING_ADMIN_KEY: &str = "pending_admin";
+pub const PENDING_ADMIN: Item<PendingAdmin> = Item::new(PENDING_ADMIN_KEY);
+
+/// Admin handover proposed by `proposer`, to be accepted by `address`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingAdmin {
+    pub address: Addr,
+    pub proposer: Addr,
+    pub expires_at_block: Option<u64>,
+}
 
 pub const ADMIN_ROLE: &str = "ADMIN_ROLE";
 pub const APPROVER_ROLE: &str = "APPROVER_ROLE";
@@ -18,8 +39,15 @@ pub enum AccessRole {
     Monitor,
 }
 
+pub const ACCESS_ROLES: [AccessRole; 4] = [
+    AccessRole::Admin,
+    AccessRole::Relayer,
+    AccessRole::Approver,
+    AccessRole::Monitor,
+];
+
 impl AccessRole {
-    fn value(&self) -> &str {
+    pub fn value(&self) -> &str {
         match *self {
             AccessRole::Admin => ADMIN_ROLE,
             AccessRole::Relayer => RELAYER_ROLE,
@@ -71,13 +99,23 @@ pub fn ac_have_role(storage: &dyn Storage, addr: &Addr, role: &AccessRole) -> St
     Ok(matches!(storage.get(&key).as_deref(), Some([1])))
 }
 
-pub fn ac_add_role(storage: &mut dyn Storage, addr: &Addr, role: &AccessRole) -> StdResult<bool> {
+pub fn ac_add_role(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    role: &AccessRole,
+) -> Result<bool, ContractError> {
     if ac_have_role(storage, addr, role).unwrap_or(false) {
-        return Err(StdError::generic_err(ERR_ACCESS_CONTROL_ALREADY_HAS_ROLE));
+        return Err(ContractError::AlreadyHasRole {});
+    }
+
+    if *role == AccessRole::Admin {
+        let admin_count = ac_admin_count(storage)?;
+        ADMIN_COUNT.save(storage, &(admin_count + 1))?;
     }
 
     let key = ac_storage_key(addr, role);
     storage.set(&key, &[1]);
+    ROLE_MEMBERS.save(storage, (role.value(), addr), &())?;
     Ok(true)
 }
 
@@ -85,12 +123,74 @@ pub fn ac_revoke_role(
     storage: &mut dyn Storage,
     addr: &Addr,
     role: &AccessRole,
-) -> StdResult<bool> {
+) -> Result<bool, ContractError> {
     if !ac_have_role(storage, addr, role).unwrap_or(false) {
-        return Err(StdError::generic_err(ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE));
+        return Err(ContractError::DoesntHaveRole {});
+    }
+    if *role == AccessRole::Admin {
+        let admin_count = ac_admin_count(storage)?;
+        if admin_count <= 1 {
+            return Err(ContractError::LastAdmin {});
+        }
+        ADMIN_COUNT.save(storage, &(admin_count - 1))?;
     }
 
     let key = ac_storage_key(addr, role);
     storage.remove(&key);
+    ROLE_MEMBERS.remove(storage, (role.value(), addr));
     Ok(true)
 }
+
+pub fn ac_admin_count(storage: &dyn Storage) -> StdResult<u64> {
+    match ADMIN_COUNT.may_load(storage)? {
+        Some(count) => Ok(count),
+        // not tracked yet, count the indexed admins
+        None => Ok(ROLE_MEMBERS
+            .prefix(AccessRole::Admin.value())
+            .keys(storage, None, None, Order::Ascending)
+            .count() as u64),
+    }
+}
+
+pub fn ac_roles_of(storage: &dyn Storage, addr: &Addr) -> StdResult<Vec<String>> {
+    let mut roles = vec![];
+    for role in &ACCESS_ROLES {
+        if ac_have_role(storage, addr, role)? {
+            roles.push(role.value().to_string());
+        }
+    }
+    Ok(roles)
+}
+
+/// Rebuilds the `ROLE_MEMBERS` index and the `ADMIN_COUNT` from the raw access
+/// control keys, needed for roles granted before the index existed
+pub fn ac_rebuild_role_index(storage: &mut dyn Storage) -> StdResult<()> {
+    let prefix = to_length_prefixed(ACCESS_CONTROL_KEY);
+    let mut end = prefix.clone();
+    if let Some(last) = end.last_mut() {
+        *last += 1;
+    }
+
+    let entries: Vec<(Addr, AccessRole)> = storage
+        .range(Some(&prefix), Some(&end), Order::Ascending)
+        .filter(|(_, value)| value.as_slice() == [1])
+        .filter_map(|(key, _)| {
+            // <len(addr): u16><addr><ROLE>
+            let rest = key.get(prefix.len()..)?;
+            let addr_len = u16::from_be_bytes([*rest.first()?, *rest.get(1)?]) as usize;
+            let addr = std::str::from_utf8(rest.get(2..2 + addr_len)?).ok()?;
+            let role = std::str::from_utf8(rest.get(2 + addr_len..)?).ok()?;
+            Some((Addr::unchecked(addr), AccessRole::from_str(role).ok()?))
+        })
+        .collect();
+
+    let mut admin_count = 0u64;
+    for (addr, role) in entries {
+        ROLE_MEMBERS.save(storage, (role.value(), &addr), &())?;
+        if role == AccessRole::Admin {
+            admin_count += 1;
+        }
+    }
+    ADMIN_COUNT.save(storage, &admin_count)?;
+    Ok(())
+}
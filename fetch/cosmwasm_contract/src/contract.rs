@@ -1,25 +1,57 @@
 use cosmwasm_std::{
-    Addr, Api, BankMsg, CanonicalAddr, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    QueryResponse, Response, StdError, StdResult, Storage, attr, entry_point, to_json_binary,
+    Addr, Api, BankMsg, CanonicalAddr, Coin, CosmosMsg, DenomMetadata, Deps, DepsMut, Env, Event,
+    MessageInfo, Order, QueryResponse, Reply, Response, StdError, StdResult, Storage, SubMsg,
+    SubMsgResult, WasmMsg, attr, entry_point, from_json, to_json_binary,
 };
+use cw_storage_plus::Bound;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
-use crate::access_control::{AccessRole, ac_add_role, ac_have_role, ac_revoke_role};
-use crate::error::{
-    ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE, ERR_ACCESS_CONTROL_ONLY_ADMIN,
-    ERR_ACCESS_CONTROL_ONLY_RELAYER, ERR_ALREADY_REFUNDED, ERR_CAP_EXCEEDED, ERR_CONTRACT_PAUSED,
-    ERR_EON, ERR_INVALID_SWAP_ID, ERR_RA_ALLOWANCE_EXCEEDED, ERR_SUPPLY_EXCEEDED,
-    ERR_SWAP_LIMITS_INCONSISTENT, ERR_SWAP_LIMITS_VIOLATED, ERR_UNRECOGNIZED_DENOM,
+use crate::access_control::{
+    AccessRole, PENDING_ADMIN, PendingAdmin, ROLE_MEMBERS, ac_add_role, ac_have_role,
+    ac_revoke_role, ac_roles_of,
 };
-use crate::helpers::{burn_tokens_from_contract, mint_tokens_to_contract};
+use crate::error::ContractError;
+use crate::helpers::{
+    burn_tokens_from_contract, change_denom_admin, create_denom, mint_tokens_to_address,
+    mint_tokens_to_contract, normalize_eth_address, set_denom_metadata, tokenfactory_denom,
+};
+use crate::migrate::{CONTRACT_NAME, CONTRACT_VERSION};
 use crate::msg::{
-    CapResponse, DenomResponse, ExecuteMsg, InstantiateMsg, PausedSinceBlockResponse, QueryMsg,
-    RelayEonResponse, ReverseAggregatedAllowanceResponse, RoleResponse, SupplyResponse,
-    SwapMaxResponse, Uint128,
+    AddressListResponse, AssetPauseStatus, AssetResponse, CapResponse, ClaimableBalance,
+    ClaimableResponse, CustodyModeResponse, DenomResponse, DustPolicyResponse, ExecuteMsg,
+    FullStateResponse, HeldPayoutsResponse, InstantiateMsg, ListAssetsResponse,
+    ListClaimableResponse, ListRoleMembersResponse, ListSwapsResponse, OperationPauseStatus,
+    OutflowCapacityResponse, PauseStatus, PauseStatusResponse, PausedSinceBlockResponse,
+    PayoutModeResponse, PendingAdminResponse, PendingAttestation, PendingAttestationsResponse,
+    PendingOperationsResponse, QueryMsg, ReceiveMsg, RefundResponse, RelayEonResponse,
+    ReverseAggregatedAllowanceResponse, ReverseSwapIdSealResponse, ReverseSwapProcessedResponse,
+    RoleResponse, RolesOfResponse, SenderUsageResponse, SupplyResponse, SwapMaxResponse,
+    SwapResponse, TokenfactoryDialectResponse, Uint128,
+};
+use crate::state::{
+    ADDRESS_LISTS, ALLOWLIST_ENABLED, ASSETS, ATTESTATIONS, AddressList, Asset, AssetKind,
+    Attestation, AttestationKind, CLAIMABLE, CONFIG, CUSTODY_MODE, CustodyMode, DENOM_RENAMES,
+    DUST_POLICY, DustPolicy, FeePolicy, HELD_PAYOUTS, MONITOR_PAUSE_MAX, NEXT_QUEUED_OPERATION_ID,
+    OUTFLOW_LIMIT, OUTFLOW_USAGE, OperationPause, OutflowLimit, OutflowUsage, PAUSES, PAYOUT_MODE,
+    PausableOperation, PauseStart, Payout, PayoutMode, QUEUED_OPERATIONS, QueuedOperation,
+    RELAYER_THRESHOLD, REVERSE_SWAP_ID_GAPS, REVERSE_SWAP_ID_ORDERING, RateWindow, RefundRecord,
+    ReverseSwapIdGap, ReverseSwapIdOrdering, SENDER_LIMITS, SENDER_USAGE, SWAPS, SWAPS_BY_SENDER,
+    SenderLimits, State, SwapRecord, TIMELOCK_DELAY, TOKENFACTORY_DIALECT, TimelockedOperation,
+    TokenfactoryDialect, address_allowed, allowlist_enabled, custody_mode, dust_policy,
+    payout_mode, refunds_add, refunds_get, refunds_have, relayer_threshold, renamed_denom,
+    reverse_swap_id_ordering, reverse_swaps_add, reverse_swaps_have, sender_usage, swaps_add,
+    timelock_delay, tokenfactory_dialect,
 };
-use crate::state::{CONFIG, State, refunds_add, refunds_have};
 
 pub const DEFAULT_DENOM: &str = "afet";
 
+// submessage paying out a reverse swap or refund
+pub const PAYOUT_REPLY_ID: u64 = 1;
+
+// pagination of list queries
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 30;
+
 /* ***************************************************
  * **************    Initialization      *************
  * ***************************************************/
@@ -30,21 +62,11 @@ pub fn instantiate(
     env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     let env_message_sender = info.sender;
-    let current_block_number = env.block.height;
 
-    let mut paused_since_block_public_api = msg.paused_since_block.unwrap_or(u64::MAX);
-    if paused_since_block_public_api < current_block_number {
-        paused_since_block_public_api = current_block_number;
-    }
-    let paused_since_block_relayer_api = paused_since_block_public_api;
-
-    let contract_addr_human = env.contract.address;
-
-    if msg.lower_swap_limit > msg.upper_swap_limit || msg.lower_swap_limit <= msg.swap_fee {
-        return Err(StdError::generic_err(ERR_SWAP_LIMITS_INCONSISTENT));
-    }
+    FeePolicy::Flat { fee: msg.swap_fee }
+        .verify_limits(msg.lower_swap_limit, msg.upper_swap_limit)?;
 
     let denom = msg.denom.unwrap_or_else(|| DEFAULT_DENOM.to_string());
 
@@ -53,24 +75,50 @@ pub fn instantiate(
     let supply = amount_from_funds(&info.funds, denom.clone());
 
     let state = State {
-        supply: supply.unwrap_or_else(|_| Uint128::zero()),
-        fees_accrued: Uint128::zero(),
         next_swap_id: msg.next_swap_id,
         sealed_reverse_swap_id: 0,
         relay_eon: 0,
+        denom: denom.clone(),
+    };
+    let asset = Asset {
+        denom,
+        kind: AssetKind::Native,
+        supply: supply.unwrap_or_else(|_| Uint128::zero()),
+        fees_accrued: Uint128::zero(),
+        cap: msg.cap,
         upper_swap_limit: msg.upper_swap_limit,
         lower_swap_limit: msg.lower_swap_limit,
+        swap_fee: msg.swap_fee,
+        fee_policy: None,
         reverse_aggregated_allowance: msg.reverse_aggregated_allowance,
         reverse_aggregated_allowance_approver_cap: msg.reverse_aggregated_allowance_approver_cap,
-        cap: msg.cap,
-        swap_fee: msg.swap_fee,
-        paused_since_block_public_api,
-        paused_since_block_relayer_api,
-        denom,
-        contract_addr_human, // optimization FIXME(LR) not needed any more (version 0.10.0)
+        paused_since_block_public_api: u64::MAX,
+        paused_since_block_relayer_api: u64::MAX,
     };
 
     CONFIG.save(deps.storage, &state)?;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
+    let paused_since_block = msg
+        .paused_since_block
+        .unwrap_or(u64::MAX)
+        .max(env.block.height);
+    for operations in [
+        &PausableOperation::PUBLIC_API[..],
+        &PausableOperation::RELAYER_API,
+    ] {
+        save_api_pause(
+            deps.storage,
+            operations,
+            paused_since_block,
+            &env_message_sender,
+        )?;
+    }
+    TOKENFACTORY_DIALECT.save(
+        deps.storage,
+        &msg.tokenfactory_dialect
+            .unwrap_or(TokenfactoryDialect::Osmosis),
+    )?;
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::default())
 }
@@ -79,14 +127,21 @@ pub fn instantiate(
  * ******************    Actions    ******************
  * ***************************************************/
 #[entry_point]
-pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
     let state = CONFIG.load(deps.storage)?;
 
     match msg {
-        ExecuteMsg::Swap { destination } => {
-            let amount = amount_from_funds(&info.funds, state.denom.clone())?;
-            try_swap(deps, &env, &state, amount, destination)
+        ExecuteMsg::Swap { destination, asset } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            let amount = native_amount(&info.funds, &asset)?;
+            try_swap(deps, &env, &info, &state, asset, amount, destination)
         }
+        ExecuteMsg::Receive(msg) => try_receive(deps, &env, &info, &state, msg),
         ExecuteMsg::ReverseSwap {
             rid,
             to,
@@ -94,142 +149,462 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
             origin_tx_hash,
             amount,
             relay_eon,
-        } => try_reverse_swap(
-            deps,
-            &env,
-            &info,
-            &state,
-            rid,
-            to,
-            sender,
-            origin_tx_hash,
-            amount,
-            relay_eon,
-        ),
+            asset,
+        } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_reverse_swap(
+                deps,
+                &env,
+                &info,
+                &state,
+                asset,
+                rid,
+                to,
+                sender,
+                origin_tx_hash,
+                amount,
+                relay_eon,
+            )
+        }
         ExecuteMsg::Refund {
             id,
             to,
             amount,
             relay_eon,
-        } => try_refund(deps, &env, &info, &state, id, to, amount, relay_eon),
+            asset,
+        } => {
+            let asset = load_refund_asset(deps.storage, &state, id, asset)?;
+            try_refund(deps, &env, &info, &state, asset, id, to, amount, relay_eon)
+        }
         ExecuteMsg::RefundInFull {
             id,
             to,
             amount,
             relay_eon,
-        } => try_refund_in_full(deps, &env, &info, &state, id, to, amount, relay_eon),
-        ExecuteMsg::PausePublicApi { since_block } => {
-            try_pause_public_api(deps, &env, &info, since_block)
+            asset,
+        } => {
+            let asset = load_refund_asset(deps.storage, &state, id, asset)?;
+            try_refund_in_full(deps, &env, &info, &state, asset, id, to, amount, relay_eon)
+        }
+        // unpausing is timelocked, pausing is not
+        ExecuteMsg::PausePublicApi { since_block, asset } if since_block > env.block.height => {
+            let operation = TimelockedOperation::UnpausePublicApi { since_block, asset };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::PausePublicApi { since_block, asset } => {
+            try_pause_public_api(deps, &env, &info, since_block, asset)
         }
-        ExecuteMsg::PauseRelayerApi { since_block } => {
-            try_pause_relayer_api(deps, &env, &info, since_block)
+        ExecuteMsg::PauseRelayerApi { since_block, asset } if since_block > env.block.height => {
+            let operation = TimelockedOperation::UnpauseRelayerApi { since_block, asset };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::PauseRelayerApi { since_block, asset } => {
+            try_pause_relayer_api(deps, &env, &info, since_block, asset)
+        }
+        ExecuteMsg::Pause {
+            operation,
+            since,
+            reason,
+        } if !since.reached(&env) => {
+            let operation = TimelockedOperation::Unpause {
+                operation,
+                since,
+                reason,
+            };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::Pause {
+            operation,
+            since,
+            reason,
+        } => try_pause(deps, &env, &info, operation, since, reason),
+        ExecuteMsg::ConfirmPause { operation } => try_confirm_pause(deps, &env, &info, operation),
+        ExecuteMsg::SetMonitorPauseMax { seconds } => {
+            try_set_monitor_pause_max(deps, &info, seconds)
+        }
+        ExecuteMsg::UpdateAddressList { list, add, remove } => {
+            try_update_address_list(deps, &info, list, add, remove)
+        }
+        ExecuteMsg::SetAllowlistEnabled { enabled } => {
+            try_set_allowlist_enabled(deps, &info, enabled)
         }
         ExecuteMsg::NewRelayEon {} => try_new_relay_eon(deps, &env, &info, &state),
-        ExecuteMsg::Deposit {} => try_deposit(deps, &info, &state),
+        ExecuteMsg::AddAsset {
+            denom,
+            kind,
+            cap,
+            upper_swap_limit,
+            lower_swap_limit,
+            swap_fee,
+            reverse_aggregated_allowance,
+            reverse_aggregated_allowance_approver_cap,
+            paused_since_block,
+        } => {
+            let paused_since_block = paused_since_block.unwrap_or(u64::MAX).max(env.block.height);
+            let kind = kind.unwrap_or(AssetKind::Native);
+            if kind == AssetKind::Cw20 {
+                deps.api.addr_validate(&denom)?;
+            }
+            let asset = Asset {
+                denom,
+                kind,
+                supply: Uint128::zero(),
+                fees_accrued: Uint128::zero(),
+                cap,
+                upper_swap_limit,
+                lower_swap_limit,
+                swap_fee,
+                fee_policy: None,
+                reverse_aggregated_allowance,
+                reverse_aggregated_allowance_approver_cap,
+                paused_since_block_public_api: paused_since_block,
+                paused_since_block_relayer_api: paused_since_block,
+            };
+            try_add_asset(deps, &info, asset)
+        }
+        ExecuteMsg::Deposit { asset } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            let amount = native_amount(&info.funds, &asset)?;
+            try_deposit(deps, &env, &info, asset, amount)
+        }
         ExecuteMsg::Withdraw {
             amount,
             destination,
-        } => try_withdraw(deps, &info, &state, amount, destination),
+            asset,
+        } => {
+            let operation = TimelockedOperation::Withdraw {
+                amount,
+                destination,
+                asset,
+            };
+            try_timelocked(deps, &env, &info, operation)
+        }
         ExecuteMsg::WithdrawFees {
             amount,
             destination,
-        } => try_withdraw_fees(deps, &info, &state, amount, destination),
-        ExecuteMsg::SetCap { amount } => try_set_cap(deps, &info, amount),
-        ExecuteMsg::SetReverseAggregatedAllowance { amount } => {
-            try_set_reverse_aggregated_allowance(deps, &info, &state, amount)
+            asset,
+        } => {
+            let operation = TimelockedOperation::WithdrawFees {
+                amount,
+                destination,
+                asset,
+            };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::SetCap { amount, asset } => try_timelocked(
+            deps,
+            &env,
+            &info,
+            TimelockedOperation::SetCap { amount, asset },
+        ),
+        ExecuteMsg::SetReverseAggregatedAllowance { amount, asset } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_set_reverse_aggregated_allowance(deps, &info, asset, amount)
         }
-        ExecuteMsg::SetReverseAggregatedAllowanceApproverCap { amount } => {
-            try_set_reverse_aggregated_allowance_approver_cap(deps, &info, amount)
+        ExecuteMsg::SetReverseAggregatedAllowanceApproverCap { amount, asset } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_set_reverse_aggregated_allowance_approver_cap(deps, &info, asset, amount)
         }
         ExecuteMsg::SetLimits {
             swap_min,
             swap_max,
             swap_fee,
-        } => try_set_limits(deps, &info, swap_min, swap_max, swap_fee),
-        ExecuteMsg::GrantRole { role, address } => try_grant_role(deps, &info, role, address),
-        ExecuteMsg::RevokeRole { role, address } => try_revoke_role(deps, &info, role, address),
+            fee_policy,
+            asset,
+        } => {
+            let operation = TimelockedOperation::SetLimits {
+                swap_min,
+                swap_max,
+                swap_fee,
+                fee_policy,
+                asset,
+            };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::GrantRole { role, address } => {
+            let operation = TimelockedOperation::GrantRole { role, address };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        // revocations are held back too, so monitors stay to cancel what is queued meanwhile
+        ExecuteMsg::RevokeRole { role, address } => {
+            let operation = TimelockedOperation::RevokeRole { role, address };
+            try_timelocked(deps, &env, &info, operation)
+        }
         ExecuteMsg::RenounceRole { role } => try_renounce_role(deps, &info, role),
-        ExecuteMsg::Mint { amount } => try_mint(deps, &env, &info, &state, amount),
-        ExecuteMsg::Burn { amount } => try_burn(deps, &env, &info, &state, amount),
+        ExecuteMsg::SetReverseSwapIdOrdering { ordering } => {
+            try_set_reverse_swap_id_ordering(deps, &info, ordering)
+        }
+        ExecuteMsg::Mint { amount, asset } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            verify_asset_kind(&asset, AssetKind::Native)?;
+            try_mint(deps, &env, &info, asset, amount)
+        }
+        ExecuteMsg::Burn { amount, asset } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            verify_asset_kind(&asset, AssetKind::Native)?;
+            try_burn(deps, &env, &info, asset, amount)
+        }
+        ExecuteMsg::ProposeAdmin {
+            address,
+            expires_at_block,
+        } => {
+            let operation = TimelockedOperation::ProposeAdmin {
+                address,
+                expires_at_block,
+            };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::AcceptAdmin {} => try_accept_admin(deps, &env, &info),
+        ExecuteMsg::CancelAdminProposal {} => try_cancel_admin_proposal(deps, &info),
+        ExecuteMsg::SetTimelockDelay { blocks } => try_timelocked(
+            deps,
+            &env,
+            &info,
+            TimelockedOperation::SetTimelockDelay { blocks },
+        ),
+        ExecuteMsg::ExecuteQueued { id } => try_execute_queued(deps, &env, &info, id),
+        ExecuteMsg::CancelQueued { id } => try_cancel_queued(deps, &info, id),
+        ExecuteMsg::SetRelayerThreshold { threshold } => {
+            let operation = TimelockedOperation::SetRelayerThreshold { threshold };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::ClearAttestations { kind, id } => try_clear_attestations(deps, &info, kind, id),
+        ExecuteMsg::SetOutflowLimit { limit, asset } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_set_outflow_limit(deps, &env, &info, &asset, limit)
+        }
+        ExecuteMsg::SetSenderLimits { limits, asset } => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_set_sender_limits(deps, &info, &asset, limits)
+        }
+        ExecuteMsg::SetDustPolicy { policy } => try_set_dust_policy(deps, &info, policy),
+        ExecuteMsg::SetCustodyMode { mode } => try_set_custody_mode(deps, &info, mode),
+        ExecuteMsg::SetPayoutMode { mode } => try_set_payout_mode(deps, &info, mode),
+        // both hand the bridged funds over to another denom or its admin
+        ExecuteMsg::CreateDenom { subdenom } => {
+            let operation = TimelockedOperation::CreateDenom { subdenom };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::ChangeDenomAdmin { new_admin, asset } => {
+            let operation = TimelockedOperation::ChangeDenomAdmin { new_admin, asset };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::SetDenomMetadata { metadata } => {
+            let asset = load_known_asset(deps.storage, &metadata.base)?;
+            try_set_denom_metadata(deps, &env, &info, asset, metadata)
+        }
+        ExecuteMsg::Claim {} => {
+            let address = info.sender.clone();
+            try_claim(deps, &env, address)
+        }
+        ExecuteMsg::ClaimFor { address } => try_claim(deps, &env, address),
+        ExecuteMsg::RedirectClaimable { address, to, asset } if info.sender == address => {
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_redirect_claimable(deps, &env, &info, asset, address, to)
+        }
+        // an admin paying out someone else's balance is held back like a withdrawal
+        ExecuteMsg::RedirectClaimable { address, to, asset } => {
+            let operation = TimelockedOperation::RedirectClaimable { address, to, asset };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::ReleaseHeldPayout {
+            operation,
+            id,
+            to: Some(to),
+        } if HELD_PAYOUTS
+            .may_load(deps.storage, (&operation, id))?
+            .is_some_and(|payout| payout.to != to) =>
+        {
+            let operation = TimelockedOperation::ReleaseHeldPayout { operation, id, to };
+            try_timelocked(deps, &env, &info, operation)
+        }
+        ExecuteMsg::ReleaseHeldPayout { operation, id, to } => {
+            try_release_held_payout(deps, &env, &info, operation, id, to)
+        }
+    }
+}
+
+/* ***************************************************
+ * ******************    Replies    ******************
+ * ***************************************************/
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        PAYOUT_REPLY_ID => reply_payout(deps, msg),
+        id => Err(StdError::generic_err(format!("unknown reply id {id}")).into()),
     }
 }
 
+// A failed payout (blocked or invalid recipient) doesn't revert the operation, which stays
+// processed, the amount is credited to the recipient to be claimed or redirected instead,
+// or held for review when the recipient isn't allowed
+fn reply_payout(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let SubMsgResult::Err(error) = msg.result else {
+        // only replied on error
+        return Ok(Response::new());
+    };
+    let payout: Payout = from_json(&msg.payload)?;
+
+    let event = Event::new("payout_failed").add_attributes(vec![
+        attr("operation", &payout.operation),
+        attr("id", payout.id.to_string()),
+        attr("to", payout.to.as_str()),
+        attr("amount", payout.amount),
+        attr("denom", &payout.denom),
+        attr("error", error),
+    ]);
+    let held = credit_or_hold(deps.storage, payout)?;
+    Ok(Response::new().add_event(event).add_events(held))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn try_swap(
     deps: DepsMut,
     env: &Env,
+    info: &MessageInfo,
     state: &State,
+    mut asset: Asset,
     amount: Uint128,
     destination: String,
-) -> StdResult<Response> {
-    verify_not_paused_public_api(env, state)?;
-    verify_swap_amount_limits(amount, state)?;
+) -> Result<Response, ContractError> {
+    verify_not_paused_operation(env, deps.storage, PausableOperation::Swap)?;
+    _verify_not_paused(env, asset.paused_since_block_public_api)?;
+    verify_swap_amount_limits(amount, &asset)?;
+    let destination = normalize_eth_address(&destination)?;
+    verify_address_allowed(deps.storage, info.sender.as_str())?;
+    verify_address_allowed(deps.storage, &destination)?;
 
-    let increased_supply = state.supply + amount;
-    if increased_supply > state.cap {
-        return Err(StdError::generic_err(ERR_CAP_EXCEEDED));
-    }
+    let minted = minting_dialect(deps.storage, &asset)?;
+    let new_supply = supply_in(&asset, amount, minted.is_some())?;
+
+    track_sender_usage(env, &asset.denom, &info.sender, amount, deps.storage)?;
 
     let swap_id = state.next_swap_id;
     CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.supply = increased_supply;
         state.next_swap_id += 1;
         Ok(state)
     })?;
+    asset.supply = new_supply;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+    let swap = SwapRecord {
+        id: swap_id,
+        denom: asset.denom.clone(),
+        sender: info.sender.clone(),
+        destination: destination.clone(),
+        amount,
+        block_height: env.block.height,
+        time: env.block.time,
+    };
+    swaps_add(deps.storage, &swap)?;
 
     let attrs = vec![
         attr("action", "swap"),
         attr("destination", destination),
         attr("swap_id", swap_id.to_string()),
         attr("amount", amount),
+        attr("denom", &asset.denom),
         // NOTE(LR) fees will be deducted in destination chain
     ];
 
-    Ok(Response::new().add_attributes(attrs))
+    let mut response = Response::new().add_attributes(attrs);
+    if let Some(dialect) = minted {
+        response = response.add_message(burn_tokens_from_contract(
+            env,
+            dialect,
+            asset.denom,
+            amount,
+        )?);
+    }
+    Ok(response)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn try_reverse_swap(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     state: &State,
+    mut asset: Asset,
     rid: u64,
     to: Addr,
     sender: String,
     origin_tx_hash: String,
     amount: Uint128,
     relay_eon: u64,
-) -> StdResult<Response> {
-    #![allow(clippy::too_many_arguments)]
+) -> Result<Response, ContractError> {
     only_relayer(info, deps.storage)?;
     verify_tx_relay_eon(relay_eon, state)?;
-    verify_not_paused_relayer_api(env, state)?;
-    verify_aggregated_reverse_allowance(amount, state)?;
-
-    if amount > state.supply {
-        return Err(StdError::generic_err(ERR_SUPPLY_EXCEEDED));
+    verify_not_paused_operation(env, deps.storage, PausableOperation::ReverseSwap)?;
+    _verify_not_paused(env, asset.paused_since_block_relayer_api)?;
+    verify_reverse_swap_id(rid, deps.storage)?;
+    verify_reverse_swap_id_order(rid, state, deps.storage)?;
+    verify_aggregated_reverse_allowance(amount, &asset)?;
+    verify_outflow_limit(env, &asset.denom, amount, deps.storage)?;
+
+    let minted = minting_dialect(deps.storage, &asset)?;
+    let new_supply = supply_out(&asset, amount, minted.is_some())?;
+
+    let swap_fee = asset.fee_for(amount);
+    let dust_policy = verify_dust_policy(amount, swap_fee, deps.storage)?;
+
+    let attestation = Attestation {
+        denom: asset.denom.clone(),
+        to: to.clone(),
+        amount,
+        origin_tx_hash: Some(origin_tx_hash.clone()),
+        relay_eon,
+        relayers: vec![],
+    };
+    if let Some(response) = attest(
+        deps.storage,
+        info,
+        AttestationKind::ReverseSwap,
+        rid,
+        attestation,
+    )? {
+        return Ok(response);
     }
+    consume_outflow(env, &asset.denom, amount, deps.storage)?;
+
+    let sealed_reverse_swap_id = seal_reverse_swap_id(deps.storage, state, rid)?;
+    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
+        state.sealed_reverse_swap_id = sealed_reverse_swap_id;
+        Ok(state)
+    })?;
 
-    if amount > state.swap_fee {
-        // NOTE(LR) when amount == fee, amount will still be consumed
-        // FIXME(LR) not fair for user IMO
-        let swap_fee = state.swap_fee;
+    if amount > swap_fee {
         let effective_amount = amount.checked_sub(swap_fee)?;
-        let to_canonical = deps.api.addr_canonicalize(to.as_str())?;
-        let rtx = send_tokens_from_contract(
-            deps.api,
-            state,
-            &to_canonical,
-            effective_amount,
-            "reverse_swap",
-        )?;
-        CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-            state.supply = state.supply.checked_sub(amount)?;
-            state.reverse_aggregated_allowance =
-                state.reverse_aggregated_allowance.checked_sub(amount)?;
-            state.fees_accrued += swap_fee;
-            //state.sealed_reverse_swap_id = rid; // TODO(LR)
-            Ok(state)
-        })?;
+        let rtx = if address_allowed(deps.storage, to.as_str())? {
+            let to_canonical = deps.api.addr_canonicalize(to.as_str())?;
+            send_payout(
+                deps.api,
+                deps.storage,
+                env,
+                &asset,
+                minted,
+                &to_canonical,
+                effective_amount,
+                "reverse_swap",
+                rid,
+            )?
+        } else {
+            let payout = Payout {
+                operation: "reverse_swap".to_string(),
+                id: rid,
+                to: to.clone(),
+                denom: asset.denom.clone(),
+                amount: effective_amount,
+            };
+            Response::new().add_event(hold_payout(deps.storage, payout)?)
+        };
+        asset.supply = new_supply;
+        asset.reverse_aggregated_allowance =
+            asset.reverse_aggregated_allowance.checked_sub(amount)?;
+        asset.fees_accrued += swap_fee;
+        ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+        reverse_swaps_add(rid, deps.storage);
 
         let attrs = vec![
             attr("action", "reverse_swap"),
@@ -239,23 +614,32 @@ fn try_reverse_swap(
             attr("origin_tx_hash", origin_tx_hash),
             attr("amount", effective_amount),
             attr("swap_fee", swap_fee),
+            attr("denom", asset.denom),
         ];
-        // FIXME(LR) store revese swap id similarly to refunds?
         Ok(Response::new()
             .add_attributes(attrs)
-            .add_submessages(rtx.messages))
+            .add_submessages(rtx.messages)
+            .add_events(rtx.events))
     } else {
-        // FIXME(LR) this unfair for the user IMO
-        let swap_fee = amount;
+        // the fee would take it all, the amount is either held for `to` or taken as fee
+        let (swap_fee, dust_events) = settle_dust(
+            deps.storage,
+            &dust_policy,
+            "reverse_swap",
+            rid,
+            &asset.denom,
+            &to,
+            amount,
+            swap_fee,
+        )?;
         let effective_amount = Uint128::zero();
-        CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-            state.supply = state.supply.checked_sub(amount)?;
-            state.reverse_aggregated_allowance =
-                state.reverse_aggregated_allowance.checked_sub(amount)?;
-            state.fees_accrued += swap_fee;
-            //state.sealed_reverse_swap_id = rid; // TODO(LR)
-            Ok(state)
-        })?;
+        asset.supply = new_supply;
+        asset.reverse_aggregated_allowance =
+            asset.reverse_aggregated_allowance.checked_sub(amount)?;
+        asset.fees_accrued += swap_fee;
+        ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+        reverse_swaps_add(rid, deps.storage);
 
         let attrs = vec![
             attr("action", "reverse_swap"),
@@ -265,9 +649,12 @@ fn try_reverse_swap(
             attr("origin_tx_hash", origin_tx_hash),
             attr("amount", effective_amount),
             attr("swap_fee", swap_fee),
+            attr("denom", asset.denom),
         ];
 
-        Ok(Response::new().add_attributes(attrs))
+        Ok(Response::new()
+            .add_attributes(attrs)
+            .add_events(dust_events))
     }
 }
 
@@ -279,44 +666,85 @@ fn try_reverse_swap(
 //    + error in the contract
 //    + on the dest chain: highly imporbable for ether and mostly probable for cosmos native
 // Refund will rebalance the `supply`
+#[allow(clippy::too_many_arguments)]
 fn _try_refund(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     state: &State,
+    mut asset: Asset,
     id: u64,
     to: Addr,
     amount: Uint128,
     relay_eon: u64,
     fee: Uint128,
-) -> StdResult<Response> {
-    #![allow(clippy::too_many_arguments)]
+    in_full: bool,
+) -> Result<Response, ContractError> {
     only_relayer(info, deps.storage)?;
     verify_tx_relay_eon(relay_eon, state)?;
-    verify_not_paused_relayer_api(env, state)?;
+    verify_not_paused_operation(env, deps.storage, PausableOperation::Refund)?;
+    _verify_not_paused(env, asset.paused_since_block_relayer_api)?;
     verify_refund_swap_id(id, deps.storage)?;
-    verify_aggregated_reverse_allowance(amount, state)?;
+    verify_refund_amount(id, amount, in_full, deps.storage)?;
+    verify_aggregated_reverse_allowance(amount, &asset)?;
+    verify_outflow_limit(env, &asset.denom, amount, deps.storage)?;
+
+    let minted = minting_dialect(deps.storage, &asset)?;
+    let new_supply = supply_out(&asset, amount, minted.is_some())?;
 
-    if amount > state.supply {
-        return Err(StdError::generic_err(ERR_SUPPLY_EXCEEDED));
+    let dust_policy = verify_dust_policy(amount, fee, deps.storage)?;
+
+    let kind = if in_full {
+        AttestationKind::RefundInFull
+    } else {
+        AttestationKind::Refund
+    };
+    let attestation = Attestation {
+        denom: asset.denom.clone(),
+        to: to.clone(),
+        amount,
+        origin_tx_hash: None,
+        relay_eon,
+        relayers: vec![],
+    };
+    if let Some(response) = attest(deps.storage, info, kind, id, attestation)? {
+        return Ok(response);
     }
+    consume_outflow(env, &asset.denom, amount, deps.storage)?;
 
     if amount > fee {
-        let new_supply = state.supply.checked_sub(amount)?;
         let effective_amount = amount.checked_sub(fee)?;
-        let to_canonical = deps.api.addr_canonicalize(to.as_str())?;
-        let rtx =
-            send_tokens_from_contract(deps.api, state, &to_canonical, effective_amount, "refund")?;
-
-        CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-            state.supply = new_supply;
-            state.reverse_aggregated_allowance =
-                state.reverse_aggregated_allowance.checked_sub(amount)?;
-            state.fees_accrued += fee;
-            Ok(state)
-        })?;
-
-        refunds_add(id, deps.storage);
+        let rtx = if address_allowed(deps.storage, to.as_str())? {
+            let to_canonical = deps.api.addr_canonicalize(to.as_str())?;
+            send_payout(
+                deps.api,
+                deps.storage,
+                env,
+                &asset,
+                minted,
+                &to_canonical,
+                effective_amount,
+                "refund",
+                id,
+            )?
+        } else {
+            let payout = Payout {
+                operation: "refund".to_string(),
+                id,
+                to: to.clone(),
+                denom: asset.denom.clone(),
+                amount: effective_amount,
+            };
+            Response::new().add_event(hold_payout(deps.storage, payout)?)
+        };
+
+        asset.supply = new_supply;
+        asset.reverse_aggregated_allowance =
+            asset.reverse_aggregated_allowance.checked_sub(amount)?;
+        asset.fees_accrued += fee;
+        ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+        refunds_add(id, &RefundRecord { amount, fee }, deps.storage)?;
 
         let attrs = vec![
             attr("action", "refund"),
@@ -324,25 +752,40 @@ fn _try_refund(
             attr("swap_id", id.to_string()),
             attr("amount", effective_amount),
             attr("refund_fee", fee),
+            attr("denom", asset.denom),
         ];
 
         Ok(Response::new()
             .add_attributes(attrs)
-            .add_submessages(rtx.messages))
+            .add_submessages(rtx.messages)
+            .add_events(rtx.events))
     } else {
-        let refund_fee = amount;
-        let new_supply = state.supply.checked_sub(amount)?;
+        let (refund_fee, dust_events) = settle_dust(
+            deps.storage,
+            &dust_policy,
+            "refund",
+            id,
+            &asset.denom,
+            &to,
+            amount,
+            fee,
+        )?;
         let effective_amount = Uint128::zero();
 
-        CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-            state.reverse_aggregated_allowance =
-                state.reverse_aggregated_allowance.checked_sub(amount)?;
-            state.supply = new_supply;
-            state.fees_accrued += refund_fee;
-            Ok(state)
-        })?;
+        asset.reverse_aggregated_allowance =
+            asset.reverse_aggregated_allowance.checked_sub(amount)?;
+        asset.supply = new_supply;
+        asset.fees_accrued += refund_fee;
+        ASSETS.save(deps.storage, &asset.denom, &asset)?;
 
-        refunds_add(id, deps.storage);
+        refunds_add(
+            id,
+            &RefundRecord {
+                amount,
+                fee: refund_fee,
+            },
+            deps.storage,
+        )?;
 
         let attrs = vec![
             attr("action", "refund"),
@@ -350,57 +793,57 @@ fn _try_refund(
             attr("swap_id", id.to_string()),
             attr("amount", effective_amount),
             attr("refund_fee", refund_fee),
+            attr("denom", asset.denom),
         ];
 
-        Ok(Response::new().add_attributes(attrs))
+        Ok(Response::new()
+            .add_attributes(attrs)
+            .add_events(dust_events))
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn try_refund(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     state: &State,
+    asset: Asset,
     id: u64,
     to: Addr,
     amount: Uint128,
     relay_eon: u64,
-) -> StdResult<Response> {
-    #![allow(clippy::too_many_arguments)]
+) -> Result<Response, ContractError> {
+    let fee = asset.fee_for(amount);
     _try_refund(
-        deps,
-        env,
-        info,
-        state,
-        id,
-        to,
-        amount,
-        relay_eon,
-        state.swap_fee,
+        deps, env, info, state, asset, id, to, amount, relay_eon, fee, false,
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn try_refund_in_full(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     state: &State,
+    asset: Asset,
     id: u64,
     to: Addr,
     amount: Uint128,
     relay_eon: u64,
-) -> StdResult<Response> {
-    #![allow(clippy::too_many_arguments)]
+) -> Result<Response, ContractError> {
     _try_refund(
         deps,
         env,
         info,
         state,
+        asset,
         id,
         to,
         amount,
         relay_eon,
         Uint128::zero(),
+        true,
     )
 }
 
@@ -409,7 +852,8 @@ fn try_pause_public_api(
     env: &Env,
     info: &MessageInfo,
     since_block: u64,
-) -> StdResult<Response> {
+    asset: Option<String>,
+) -> Result<Response, ContractError> {
     can_pause(env, info, deps.storage, since_block)?;
 
     let pause_since_block = if since_block < env.block.height {
@@ -417,15 +861,24 @@ fn try_pause_public_api(
     } else {
         since_block
     };
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.paused_since_block_public_api = pause_since_block;
-        Ok(state)
-    })?;
-
-    let attrs = vec![
+    let mut attrs = vec![
         attr("action", "pause_public_api"),
         attr("since_block", pause_since_block.to_string()),
     ];
+    match asset {
+        None => {
+            for operation in PausableOperation::PUBLIC_API {
+                let since = PauseStart::Height(since_block);
+                set_pause(deps.storage, env, info, operation, since, None)?;
+            }
+        }
+        Some(denom) => {
+            let mut asset = load_known_asset(deps.storage, &denom)?;
+            asset.paused_since_block_public_api = pause_since_block;
+            ASSETS.save(deps.storage, &denom, &asset)?;
+            attrs.push(attr("asset", denom));
+        }
+    }
 
     Ok(Response::new().add_attributes(attrs))
 }
@@ -435,7 +888,8 @@ fn try_pause_relayer_api(
     env: &Env,
     info: &MessageInfo,
     since_block: u64,
-) -> StdResult<Response> {
+    asset: Option<String>,
+) -> Result<Response, ContractError> {
     can_pause(env, info, deps.storage, since_block)?;
 
     let pause_since_block = if since_block < env.block.height {
@@ -443,190 +897,508 @@ fn try_pause_relayer_api(
     } else {
         since_block
     };
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.paused_since_block_relayer_api = pause_since_block;
-        Ok(state)
-    })?;
-
-    let attrs = vec![
+    let mut attrs = vec![
         attr("action", "pause_relayer_api"),
         attr("since_block", pause_since_block.to_string()),
     ];
+    match asset {
+        None => {
+            for operation in PausableOperation::RELAYER_API {
+                let since = PauseStart::Height(since_block);
+                set_pause(deps.storage, env, info, operation, since, None)?;
+            }
+        }
+        Some(denom) => {
+            let mut asset = load_known_asset(deps.storage, &denom)?;
+            asset.paused_since_block_relayer_api = pause_since_block;
+            ASSETS.save(deps.storage, &denom, &asset)?;
+            attrs.push(attr("asset", denom));
+        }
+    }
 
     Ok(Response::new().add_attributes(attrs))
 }
 
-fn try_new_relay_eon(
+fn try_pause(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
-    state: &State,
-) -> StdResult<Response> {
-    only_relayer(info, deps.storage)?;
-    verify_not_paused_relayer_api(env, state)?;
-
-    let new_eon = state.relay_eon + 1;
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.relay_eon = new_eon; // FIXME(LR) starts from 1
-        Ok(state)
-    })?;
-
-    let attrs = vec![
-        attr("action", "new_relay_eon"),
-        attr("eon", new_eon.to_string()),
+    operation: PausableOperation,
+    since: PauseStart,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    let pause = set_pause(deps.storage, env, info, operation, since, reason)?;
+
+    let mut attrs = vec![
+        attr("action", "pause"),
+        attr("operation", operation.name()),
+        attr("since", pause.since.to_string()),
+        attr("reason", pause.reason),
     ];
+    if let Some(expires_at) = pause.expires_at {
+        attrs.push(attr("expires_at", expires_at.seconds().to_string()));
+    }
 
     Ok(Response::new().add_attributes(attrs))
 }
 
-fn try_deposit(deps: DepsMut, info: &MessageInfo, state: &State) -> StdResult<Response> {
-    only_admin(info, deps.storage)?;
+// Pauses by monitors lapse after the configured maximum unless an admin confirms them,
+// unpausing is left to admins
+fn set_pause(
+    storage: &mut dyn Storage,
+    env: &Env,
+    info: &MessageInfo,
+    operation: PausableOperation,
+    since: PauseStart,
+    reason: Option<String>,
+) -> Result<OperationPause, ContractError> {
+    let is_admin = only_admin(info, storage).is_ok();
+    let since = if since.reached(env) {
+        // pausing, from now on
+        if !is_admin {
+            only_monitor(info, storage).or_else(|_| only_admin(info, storage))?;
+            // a monitor's pause would lapse, it can't replace one that doesn't
+            let existing = PAUSES.may_load(storage, operation.name())?;
+            if existing.is_some_and(|pause| pause.active(env) && pause.expires_at.is_none()) {
+                only_admin(info, storage)?;
+            }
+        }
+        match since {
+            PauseStart::Height(_) => PauseStart::Height(env.block.height),
+            PauseStart::Time(_) => PauseStart::Time(env.block.time),
+        }
+    } else {
+        // unpausing
+        only_admin(info, storage)?;
+        since
+    };
 
-    let env_message_sender = &info.sender;
+    let expires_at = match MONITOR_PAUSE_MAX.may_load(storage)? {
+        Some(seconds) if !is_admin => Some(env.block.time.plus_seconds(seconds)),
+        _ => None,
+    };
+    let pause = OperationPause {
+        since,
+        reason: reason.unwrap_or_default(),
+        paused_by: info.sender.clone(),
+        expires_at,
+    };
+    PAUSES.save(storage, operation.name(), &pause)?;
+    Ok(pause)
+}
 
-    let amount = amount_from_funds(&info.funds, state.denom.clone())?;
+// Pause of the operations of an API in the form of the instantiation and migration
+// messages, from a block, u64::MAX lifting it
+pub(crate) fn save_api_pause(
+    storage: &mut dyn Storage,
+    operations: &[PausableOperation],
+    since_block: u64,
+    paused_by: &Addr,
+) -> StdResult<()> {
+    for operation in operations {
+        if since_block == u64::MAX {
+            PAUSES.remove(storage, operation.name());
+            continue;
+        }
+        let pause = OperationPause {
+            since: PauseStart::Height(since_block),
+            reason: String::new(),
+            paused_by: paused_by.clone(),
+            expires_at: None,
+        };
+        PAUSES.save(storage, operation.name(), &pause)?;
+    }
+    Ok(())
+}
 
-    let increased_supply = state.supply + amount;
-    if increased_supply > state.cap {
-        return Err(StdError::generic_err(ERR_CAP_EXCEEDED));
+// Block from which all the operations of an API are paused, u64::MAX if one isn't (a
+// pause from a time reports the current block once reached)
+fn api_paused_since_block(
+    storage: &dyn Storage,
+    env: &Env,
+    operations: &[PausableOperation],
+) -> StdResult<u64> {
+    let mut paused_since_block = 0;
+    for operation in operations {
+        let since_block = match PAUSES.may_load(storage, operation.name())? {
+            Some(pause) if pause.expires_at.is_some_and(|at| env.block.time >= at) => u64::MAX,
+            Some(OperationPause {
+                since: PauseStart::Height(height),
+                ..
+            }) => height,
+            Some(pause) if pause.since.reached(env) => env.block.height,
+            _ => u64::MAX,
+        };
+        paused_since_block = paused_since_block.max(since_block);
     }
+    Ok(paused_since_block)
+}
 
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.supply = increased_supply;
-        Ok(state)
-    })?;
+fn try_confirm_pause(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    operation: PausableOperation,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    let mut pause = PAUSES
+        .may_load(deps.storage, operation.name())?
+        .filter(|pause| pause.expires_at.is_some() && pause.active(env))
+        .ok_or(ContractError::PauseNotExpiring {})?;
+    pause.expires_at = None;
+    PAUSES.save(deps.storage, operation.name(), &pause)?;
 
     let attrs = vec![
-        attr("action", "deposit"),
-        attr("amount", amount),
-        attr("sender", env_message_sender.as_str()),
+        attr("action", "confirm_pause"),
+        attr("operation", operation.name()),
     ];
 
     Ok(Response::new().add_attributes(attrs))
 }
 
-fn try_withdraw(
+// Blocking is open to monitors like pausing, anything letting addresses through is left to admins
+fn try_update_address_list(
     deps: DepsMut,
     info: &MessageInfo,
-    state: &State,
-    amount: Uint128,
-    destination: Addr,
-) -> StdResult<Response> {
-    only_admin(info, deps.storage)?;
-
-    if amount > state.supply {
-        return Err(StdError::generic_err(ERR_SUPPLY_EXCEEDED));
+    list: AddressList,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let lets_through = match list {
+        AddressList::Blocked => !remove.is_empty(),
+        AddressList::Allowed => !add.is_empty(),
+    };
+    if lets_through {
+        only_admin(info, deps.storage)?;
+    } else {
+        only_monitor(info, deps.storage).or_else(|_| only_admin(info, deps.storage))?;
     }
 
-    let new_supply = state.supply.checked_sub(amount)?;
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.supply = new_supply;
-        Ok(state)
-    })?;
-    let recipient = deps.api.addr_canonicalize(destination.as_str())?;
-    let wtx = send_tokens_from_contract(deps.api, state, &recipient, amount, "withdraw")?;
-
-    let attrs = vec![
-        attr("action", "withdraw"),
-        attr("amount", amount),
-        attr("destination", destination.as_str()),
+    let mut attrs = vec![
+        attr("action", "update_address_list"),
+        attr("list", list.name()),
     ];
+    for address in add {
+        let address = listed_address(deps.api, &address)?;
+        ADDRESS_LISTS.save(deps.storage, (list.name(), &address), &())?;
+        attrs.push(attr("added", address));
+    }
+    for address in remove {
+        let address = listed_address(deps.api, &address)?;
+        ADDRESS_LISTS.remove(deps.storage, (list.name(), &address));
+        attrs.push(attr("removed", address));
+    }
 
-    Ok(Response::new()
-        .add_attributes(attrs)
-        .add_submessages(wtx.messages))
+    Ok(Response::new().add_attributes(attrs))
 }
 
-fn try_withdraw_fees(
+fn try_set_allowlist_enabled(
     deps: DepsMut,
     info: &MessageInfo,
-    state: &State,
-    amount: Uint128,
-    destination: Addr,
-) -> StdResult<Response> {
+    enabled: bool,
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage)?;
 
-    if amount > state.fees_accrued {
-        return Err(StdError::generic_err(ERR_SUPPLY_EXCEEDED));
-    }
-
-    let new_fees_accrued = state.fees_accrued.checked_sub(amount)?;
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.fees_accrued = new_fees_accrued;
-        Ok(state)
-    })?;
-
-    let recipient = deps.api.addr_canonicalize(destination.as_str())?;
-    let wtx = send_tokens_from_contract(deps.api, state, &recipient, amount, "withdraw_fees")?;
+    ALLOWLIST_ENABLED.save(deps.storage, &enabled)?;
 
     let attrs = vec![
-        attr("action", "withdraw_fees"),
-        attr("amount", amount),
-        attr("destination", destination.as_str()),
+        attr("action", "set_allowlist_enabled"),
+        attr("enabled", enabled.to_string()),
     ];
+    Ok(Response::new().add_attributes(attrs))
+}
 
-    Ok(Response::new()
-        .add_attributes(attrs)
-        .add_submessages(wtx.messages))
+// Ethereum addresses are listed checksummed, as swap destinations are
+fn listed_address(api: &dyn Api, address: &str) -> Result<String, ContractError> {
+    if address.starts_with("0x") {
+        return normalize_eth_address(address);
+    }
+    Ok(api.addr_validate(address)?.to_string())
 }
 
-fn try_mint(
+fn try_set_monitor_pause_max(
     deps: DepsMut,
-    env: &Env,
     info: &MessageInfo,
-    state: &State,
-    amount: Uint128,
-) -> StdResult<Response> {
+    seconds: Option<u64>,
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage)?;
 
-    let increased_supply = state.supply + amount;
-    if increased_supply > state.cap {
-        return Err(StdError::generic_err(ERR_CAP_EXCEEDED));
+    let mut attrs = vec![attr("action", "set_monitor_pause_max")];
+    match seconds {
+        Some(seconds) => {
+            MONITOR_PAUSE_MAX.save(deps.storage, &seconds)?;
+            attrs.push(attr("seconds", seconds.to_string()));
+        }
+        None => MONITOR_PAUSE_MAX.remove(deps.storage),
     }
 
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.supply = increased_supply;
-        Ok(state)
-    })?;
-
-    let msg = mint_tokens_to_contract(env, state.denom.clone(), amount)?;
-    let attrs = vec![attr("action", "mint"), attr("amount", amount)];
-
-    Ok(Response::new().add_attributes(attrs).add_message(msg))
+    Ok(Response::new().add_attributes(attrs))
 }
 
-fn try_burn(
+fn try_new_relay_eon(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    state: &State,
+) -> Result<Response, ContractError> {
+    only_relayer(info, deps.storage)?;
+    verify_not_paused_relayer_api(env, deps.storage)?;
+
+    let new_eon = state.relay_eon + 1;
+    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
+        state.relay_eon = new_eon; // FIXME(LR) starts from 1
+        Ok(state)
+    })?;
+
+    let attrs = vec![
+        attr("action", "new_relay_eon"),
+        attr("eon", new_eon.to_string()),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_add_asset(
+    deps: DepsMut,
+    info: &MessageInfo,
+    asset: Asset,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    if ASSETS.has(deps.storage, &asset.denom) {
+        return Err(ContractError::AssetAlreadyExists { denom: asset.denom });
+    }
+    FeePolicy::Flat {
+        fee: asset.swap_fee,
+    }
+    .verify_limits(asset.lower_swap_limit, asset.upper_swap_limit)?;
+
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+    let attrs = vec![
+        attr("action", "add_asset"),
+        attr("denom", asset.denom),
+        attr(
+            "paused_since_block",
+            asset.paused_since_block_public_api.to_string(),
+        ),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+// CW20 tokens are received from their contract on behalf of `msg.sender`, who is
+// then the sender of the embedded message
+fn try_receive(
     deps: DepsMut,
     env: &Env,
     info: &MessageInfo,
     state: &State,
+    msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let asset = load_known_asset(deps.storage, info.sender.as_str())?;
+    verify_asset_kind(&asset, AssetKind::Cw20)?;
+
+    let info = MessageInfo {
+        sender: deps.api.addr_validate(&msg.sender)?,
+        funds: vec![],
+    };
+    match from_json(&msg.msg)? {
+        ReceiveMsg::Swap { destination } => {
+            try_swap(deps, env, &info, state, asset, msg.amount, destination)
+        }
+        ReceiveMsg::Deposit {} => try_deposit(deps, env, &info, asset, msg.amount),
+    }
+}
+
+fn try_deposit(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    mut asset: Asset,
     amount: Uint128,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage)?;
+    verify_not_paused_operation(env, deps.storage, PausableOperation::Deposit)?;
+    // nothing is held by the contract to back its supply
+    if minting_dialect(deps.storage, &asset)?.is_some() {
+        return Err(ContractError::CustodyModeUnsupported {});
+    }
+
+    let env_message_sender = &info.sender;
 
-    if amount > state.supply {
-        return Err(StdError::generic_err(ERR_SUPPLY_EXCEEDED));
+    let increased_supply = asset.supply + amount;
+    if increased_supply > asset.cap {
+        return Err(ContractError::CapExceeded {
+            supply: increased_supply,
+            cap: asset.cap,
+        });
     }
 
-    let new_supply = state.supply.checked_sub(amount)?;
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.supply = new_supply;
-        Ok(state)
-    })?;
+    asset.supply = increased_supply;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+    let attrs = vec![
+        attr("action", "deposit"),
+        attr("amount", amount),
+        attr("sender", env_message_sender.as_str()),
+        attr("denom", asset.denom),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_withdraw(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    mut asset: Asset,
+    amount: Uint128,
+    destination: Addr,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+    verify_not_paused_operation(env, deps.storage, PausableOperation::Withdraw)?;
+    if minting_dialect(deps.storage, &asset)?.is_some() {
+        return Err(ContractError::CustodyModeUnsupported {});
+    }
+
+    if amount > asset.supply {
+        return Err(ContractError::SupplyExceeded {
+            requested: amount,
+            available: asset.supply,
+        });
+    }
+
+    asset.supply = asset.supply.checked_sub(amount)?;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
+    let recipient = deps.api.addr_canonicalize(destination.as_str())?;
+    let wtx =
+        send_tokens_from_contract(deps.api, env, &asset, None, &recipient, amount, "withdraw")?;
+
+    let attrs = vec![
+        attr("action", "withdraw"),
+        attr("amount", amount),
+        attr("destination", destination.as_str()),
+        attr("denom", asset.denom),
+    ];
+
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_submessages(wtx.messages))
+}
+
+fn try_withdraw_fees(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    mut asset: Asset,
+    amount: Uint128,
+    destination: Addr,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+    verify_not_paused_operation(env, deps.storage, PausableOperation::Withdraw)?;
+
+    if amount > asset.fees_accrued {
+        return Err(ContractError::SupplyExceeded {
+            requested: amount,
+            available: asset.fees_accrued,
+        });
+    }
+
+    asset.fees_accrued = asset.fees_accrued.checked_sub(amount)?;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+    // fees are part of the supply issued in mint/burn mode, they are minted once withdrawn
+    let minted = minting_dialect(deps.storage, &asset)?;
+    let recipient = deps.api.addr_canonicalize(destination.as_str())?;
+    let wtx = send_tokens_from_contract(
+        deps.api,
+        env,
+        &asset,
+        minted,
+        &recipient,
+        amount,
+        "withdraw_fees",
+    )?;
+
+    let attrs = vec![
+        attr("action", "withdraw_fees"),
+        attr("amount", amount),
+        attr("destination", destination.as_str()),
+        attr("denom", asset.denom),
+    ];
+
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_submessages(wtx.messages))
+}
+
+fn try_mint(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    mut asset: Asset,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+    verify_not_paused_operation(env, deps.storage, PausableOperation::MintBurn)?;
+
+    let increased_supply = asset.supply + amount;
+    if increased_supply > asset.cap {
+        return Err(ContractError::CapExceeded {
+            supply: increased_supply,
+            cap: asset.cap,
+        });
+    }
+
+    asset.supply = increased_supply;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+    let dialect = tokenfactory_dialect(deps.storage)?;
+    let msg = mint_tokens_to_contract(env, dialect, asset.denom.clone(), amount)?;
+    let attrs = vec![attr("action", "mint"), attr("amount", amount)];
+
+    Ok(Response::new().add_attributes(attrs).add_message(msg))
+}
+
+fn try_burn(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    mut asset: Asset,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+    verify_not_paused_operation(env, deps.storage, PausableOperation::MintBurn)?;
 
-    let msg = burn_tokens_from_contract(env, state.denom.clone(), amount)?;
+    if amount > asset.supply {
+        return Err(ContractError::SupplyExceeded {
+            requested: amount,
+            available: asset.supply,
+        });
+    }
+
+    asset.supply = asset.supply.checked_sub(amount)?;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
+
+    let dialect = tokenfactory_dialect(deps.storage)?;
+    let msg = burn_tokens_from_contract(env, dialect, asset.denom.clone(), amount)?;
     let attrs = vec![attr("action", "burn"), attr("amount", amount)];
 
     Ok(Response::new().add_attributes(attrs).add_message(msg))
 }
 
-fn try_set_cap(deps: DepsMut, info: &MessageInfo, amount: Uint128) -> StdResult<Response> {
+fn try_set_cap(
+    deps: DepsMut,
+    info: &MessageInfo,
+    mut asset: Asset,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage)?;
 
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.cap = amount;
-        Ok(state)
-    })?;
+    asset.cap = amount;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
 
     let attrs = vec![attr("action", "set_cap"), attr("cap", amount)];
 
@@ -636,21 +1408,19 @@ fn try_set_cap(deps: DepsMut, info: &MessageInfo, amount: Uint128) -> StdResult<
 fn try_set_reverse_aggregated_allowance(
     deps: DepsMut,
     info: &MessageInfo,
-    state: &State,
+    mut asset: Asset,
     amount: Uint128,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage).or(
-        if amount <= state.reverse_aggregated_allowance_approver_cap {
+        if amount <= asset.reverse_aggregated_allowance_approver_cap {
             only_approver(info, deps.storage)
         } else {
-            Err(StdError::generic_err(ERR_ACCESS_CONTROL_ONLY_ADMIN))
+            Err(ContractError::OnlyAdmin {})
         },
     )?;
 
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.reverse_aggregated_allowance = amount;
-        Ok(state)
-    })?;
+    asset.reverse_aggregated_allowance = amount;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
 
     let attrs = vec![
         attr("action", "set_reverse_aggregated_allowance"),
@@ -663,14 +1433,13 @@ fn try_set_reverse_aggregated_allowance(
 fn try_set_reverse_aggregated_allowance_approver_cap(
     deps: DepsMut,
     info: &MessageInfo,
+    mut asset: Asset,
     amount: Uint128,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage)?;
 
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.reverse_aggregated_allowance_approver_cap = amount;
-        Ok(state)
-    })?;
+    asset.reverse_aggregated_allowance_approver_cap = amount;
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
 
     let attrs = vec![
         attr("action", "set_reverse_aggregated_allowance_approver_cap"),
@@ -683,28 +1452,30 @@ fn try_set_reverse_aggregated_allowance_approver_cap(
 fn try_set_limits(
     deps: DepsMut,
     info: &MessageInfo,
+    mut asset: Asset,
     swap_min: Uint128,
     swap_max: Uint128,
     swap_fee: Uint128,
-) -> StdResult<Response> {
+    fee_policy: Option<FeePolicy>,
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage)?;
 
-    if swap_min <= swap_fee || swap_min > swap_max {
-        return Err(StdError::generic_err(ERR_SWAP_LIMITS_INCONSISTENT));
-    }
-    CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
-        state.swap_fee = swap_fee;
-        state.lower_swap_limit = swap_min;
-        state.upper_swap_limit = swap_max;
-        Ok(state)
-    })?;
+    verify_limits(swap_min, swap_max, swap_fee, &fee_policy)?;
+    asset.swap_fee = swap_fee;
+    asset.lower_swap_limit = swap_min;
+    asset.upper_swap_limit = swap_max;
+    asset.fee_policy = fee_policy.clone();
+    ASSETS.save(deps.storage, &asset.denom, &asset)?;
 
-    let attrs = vec![
+    let mut attrs = vec![
         attr("action", "set_limits"),
         attr("swap_fee", swap_fee),
         attr("swap_min", swap_min),
         attr("swap_max", swap_max),
     ];
+    if let Some(fee_policy) = fee_policy {
+        attrs.push(attr("fee_policy", fee_policy_name(&fee_policy)));
+    }
 
     Ok(Response::new().add_attributes(attrs))
 }
@@ -714,7 +1485,7 @@ fn try_grant_role(
     info: &MessageInfo,
     role: String,
     address: Addr,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage)?;
 
     ac_add_role(
@@ -737,7 +1508,7 @@ fn try_revoke_role(
     info: &MessageInfo,
     role: String,
     address: Addr,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     only_admin(info, deps.storage)?;
 
     ac_revoke_role(
@@ -755,13 +1526,17 @@ fn try_revoke_role(
     Ok(Response::new().add_attributes(attrs))
 }
 
-fn try_renounce_role(deps: DepsMut, info: &MessageInfo, role: String) -> StdResult<Response> {
+fn try_renounce_role(
+    deps: DepsMut,
+    info: &MessageInfo,
+    role: String,
+) -> Result<Response, ContractError> {
     let env_message_sender = &info.sender;
 
     let ac_role = &AccessRole::from_str(role.as_str())?;
     let have_role = ac_have_role(deps.storage, env_message_sender, ac_role).unwrap_or(false);
     if !have_role {
-        return Err(StdError::generic_err(ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE));
+        return Err(ContractError::DoesntHaveRole {});
     }
     ac_revoke_role(deps.storage, env_message_sender, ac_role)?;
 
@@ -774,145 +1549,1506 @@ fn try_renounce_role(deps: DepsMut, info: &MessageInfo, role: String) -> StdResu
     Ok(Response::new().add_attributes(attrs))
 }
 
-/* ***************************************************
- * *****************    Helpers      *****************
- * ***************************************************/
+fn try_propose_admin(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    proposer: &Addr,
+    address: Addr,
+    expires_at_block: Option<u64>,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
 
-pub fn amount_from_funds(funds: &[Coin], denom: String) -> StdResult<Uint128> {
-    for coin in funds {
-        if coin.denom == denom {
-            return Ok(coin.amount);
-        }
+    if ac_have_role(deps.storage, &address, &AccessRole::Admin)? {
+        return Err(ContractError::AlreadyHasRole {});
     }
-    Err(StdError::generic_err(ERR_UNRECOGNIZED_DENOM))
-}
-
-fn send_tokens_from_contract(
-    api: &dyn Api,
-    state: &State,
-    to_address: &CanonicalAddr,
-    amount: Uint128,
-    action: &str,
-) -> Result<Response, StdError> {
-    let to_human = api.addr_humanize(to_address)?;
-    let attrs = vec![attr("action", action), attr("to", to_human.as_str())];
-    let msg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: to_human.to_string(),
-        amount: vec![Coin {
-            amount,
-            denom: state.denom.clone(),
-        }],
-    });
-    Ok(Response::new().add_attributes(attrs).add_message(msg))
-}
-
-/* ***************************************************
- * ***************    Verifiers      *****************
- * ***************************************************/
-
-fn verify_tx_relay_eon(eon: u64, state: &State) -> Result<Response, StdError> {
-    if eon != state.relay_eon {
-        Err(StdError::generic_err(ERR_EON))
-    } else {
-        Ok(Response::default())
+    if let Some(expires_at_block) = expires_at_block
+        && expires_at_block < env.block.height
+    {
+        return Err(ContractError::AdminProposalExpired { expires_at_block });
     }
-}
-
-pub fn verify_not_paused_public_api(env: &Env, state: &State) -> Result<Response, StdError> {
-    _verify_not_paused(env, state.paused_since_block_public_api)
-}
 
-pub fn verify_not_paused_relayer_api(env: &Env, state: &State) -> Result<Response, StdError> {
-    _verify_not_paused(env, state.paused_since_block_relayer_api)
-}
+    let pending_admin = PendingAdmin {
+        address,
+        proposer: proposer.clone(),
+        expires_at_block,
+    };
+    PENDING_ADMIN.save(deps.storage, &pending_admin)?;
 
-fn _verify_not_paused(env: &Env, paused_since_block: u64) -> Result<Response, StdError> {
-    if env.block.height < paused_since_block {
-        Ok(Response::default())
-    } else {
-        Err(StdError::generic_err(ERR_CONTRACT_PAUSED))
-    }
-}
+    let attrs = vec![
+        attr("action", "propose_admin"),
+        attr("account", pending_admin.address.as_str()),
+        attr(
+            "expires_at_block",
+            expires_at_block.map_or("never".to_string(), |b| b.to_string()),
+        ),
+    ];
 
-fn verify_swap_amount_limits(amount: Uint128, state: &State) -> Result<Response, StdError> {
-    if amount < state.lower_swap_limit || amount > state.upper_swap_limit {
-        Err(StdError::generic_err(ERR_SWAP_LIMITS_VIOLATED))
-    } else {
-        Ok(Response::default())
-    }
+    Ok(Response::new().add_attributes(attrs))
 }
 
-fn verify_aggregated_reverse_allowance(
-    amount: Uint128,
-    state: &State,
-) -> Result<Response, StdError> {
-    if state.reverse_aggregated_allowance < amount {
-        Err(StdError::generic_err(ERR_RA_ALLOWANCE_EXCEEDED))
-    } else {
-        Ok(Response::default())
+// the proposer hands the admin role over to the accepting account
+fn try_accept_admin(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending_admin = match PENDING_ADMIN.may_load(deps.storage)? {
+        Some(pending_admin) if pending_admin.address == info.sender => pending_admin,
+        _ => return Err(ContractError::NotPendingAdmin {}),
+    };
+    if let Some(expires_at_block) = pending_admin.expires_at_block
+        && expires_at_block < env.block.height
+    {
+        return Err(ContractError::AdminProposalExpired { expires_at_block });
     }
-}
 
-fn verify_refund_swap_id(id: u64, storage: &dyn Storage) -> Result<Response, StdError> {
-    let state = CONFIG.load(storage)?;
-    if id >= state.next_swap_id {
-        // FIXME(LR) >= ?
-        return Err(StdError::generic_err(ERR_INVALID_SWAP_ID));
+    ac_add_role(deps.storage, &info.sender, &AccessRole::Admin)?;
+    if ac_have_role(deps.storage, &pending_admin.proposer, &AccessRole::Admin)? {
+        ac_revoke_role(deps.storage, &pending_admin.proposer, &AccessRole::Admin)?;
     }
-    match refunds_have(id, storage) {
-        true => Err(StdError::generic_err(ERR_ALREADY_REFUNDED)),
-        false => Ok(Response::default()),
-    }
-}
+    PENDING_ADMIN.remove(deps.storage);
 
-/* ***************************************************
- * ************    Access Control      ***************
- * ***************************************************/
+    let attrs = vec![
+        attr("action", "accept_admin"),
+        attr("account", info.sender.as_str()),
+        attr("previous_admin", pending_admin.proposer.as_str()),
+    ];
 
-fn only_admin(info: &MessageInfo, storage: &dyn Storage) -> Result<Response, StdError> {
-    _only_role(&AccessRole::Admin, info, storage)
+    Ok(Response::new().add_attributes(attrs))
 }
 
-fn only_relayer(info: &MessageInfo, storage: &dyn Storage) -> Result<Response, StdError> {
-    _only_role(&AccessRole::Relayer, info, storage)
-}
+fn try_cancel_admin_proposal(deps: DepsMut, info: &MessageInfo) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
 
-fn only_approver(info: &MessageInfo, storage: &dyn Storage) -> Result<Response, StdError> {
-    _only_role(&AccessRole::Approver, info, storage)
-}
+    PENDING_ADMIN.remove(deps.storage);
 
-fn only_monitor(info: &MessageInfo, storage: &dyn Storage) -> Result<Response, StdError> {
-    _only_role(&AccessRole::Monitor, info, storage)
+    Ok(Response::new().add_attributes(vec![attr("action", "cancel_admin_proposal")]))
 }
 
-fn _only_role(
-    role: &AccessRole,
+// Runs `operation` right away when no timelock delay is set, queues it otherwise
+fn try_timelocked(
+    deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
-    storage: &dyn Storage,
-) -> Result<Response, StdError> {
-    let env_message_sender = &info.sender;
+    operation: TimelockedOperation,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
 
-    match ac_have_role(storage, env_message_sender, role) {
-        Ok(has_role) => match has_role {
-            true => Ok(Response::default()),
-            false => Err(StdError::generic_err(match role {
-                AccessRole::Admin => ERR_ACCESS_CONTROL_ONLY_ADMIN,
-                AccessRole::Relayer => ERR_ACCESS_CONTROL_ONLY_RELAYER,
-                _ => ERR_ACCESS_CONTROL_ONLY_ADMIN,
-            })),
-        },
-        Err(err) => Err(err),
+    let delay = timelock_delay(deps.storage)?;
+    if delay == 0 {
+        return run_operation(deps, env, info, &info.sender, operation);
     }
-}
 
-fn can_pause(
-    env: &Env,
-    info: &MessageInfo,
-    storage: &dyn Storage,
-    since_block: u64,
-) -> Result<Response, StdError> {
-    if since_block > env.block.height {
-        // unpausing
+    // reject what would fail anyway once executed
+    match &operation {
+        TimelockedOperation::SetLimits {
+            swap_min,
+            swap_max,
+            swap_fee,
+            fee_policy,
+            ..
+        } => {
+            verify_limits(*swap_min, *swap_max, *swap_fee, fee_policy)?;
+        }
+        TimelockedOperation::GrantRole { role, .. }
+        | TimelockedOperation::RevokeRole { role, .. } => {
+            AccessRole::from_str(role.as_str())?;
+        }
+        TimelockedOperation::SetRelayerThreshold { threshold: 0 } => {
+            return Err(ContractError::InvalidRelayerThreshold {});
+        }
+        _ => {}
+    }
+
+    let id = NEXT_QUEUED_OPERATION_ID
+        .may_load(deps.storage)?
+        .unwrap_or(0);
+    NEXT_QUEUED_OPERATION_ID.save(deps.storage, &(id + 1))?;
+
+    let queued = QueuedOperation {
+        id,
+        operation,
+        proposer: info.sender.clone(),
+        queued_at_block: env.block.height,
+        execute_at_block: env.block.height.saturating_add(delay),
+    };
+    QUEUED_OPERATIONS.save(deps.storage, id, &queued)?;
+
+    let attrs = vec![
+        attr("action", "queue_operation"),
+        attr("id", id.to_string()),
+        attr("execute_at_block", queued.execute_at_block.to_string()),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+// `proposer` is the admin who queued the operation, `info` the one running it
+fn run_operation(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    proposer: &Addr,
+    operation: TimelockedOperation,
+) -> Result<Response, ContractError> {
+    match operation {
+        TimelockedOperation::Withdraw {
+            amount,
+            destination,
+            asset,
+        } => {
+            let state = CONFIG.load(deps.storage)?;
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_withdraw(deps, env, info, asset, amount, destination)
+        }
+        TimelockedOperation::WithdrawFees {
+            amount,
+            destination,
+            asset,
+        } => {
+            let state = CONFIG.load(deps.storage)?;
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_withdraw_fees(deps, env, info, asset, amount, destination)
+        }
+        TimelockedOperation::RedirectClaimable { address, to, asset } => {
+            let state = CONFIG.load(deps.storage)?;
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_redirect_claimable(deps, env, info, asset, address, to)
+        }
+        TimelockedOperation::CreateDenom { subdenom } => {
+            let state = CONFIG.load(deps.storage)?;
+            try_create_denom(deps, env, info, state, subdenom)
+        }
+        TimelockedOperation::ChangeDenomAdmin { new_admin, asset } => {
+            let state = CONFIG.load(deps.storage)?;
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_change_denom_admin(deps, env, info, asset, new_admin)
+        }
+        TimelockedOperation::ReleaseHeldPayout { operation, id, to } => {
+            try_release_held_payout(deps, env, info, operation, id, Some(to))
+        }
+        TimelockedOperation::SetCap { amount, asset } => {
+            let state = CONFIG.load(deps.storage)?;
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_set_cap(deps, info, asset, amount)
+        }
+        TimelockedOperation::SetLimits {
+            swap_min,
+            swap_max,
+            swap_fee,
+            fee_policy,
+            asset,
+        } => {
+            let state = CONFIG.load(deps.storage)?;
+            let asset = load_asset(deps.storage, &state, asset)?;
+            try_set_limits(deps, info, asset, swap_min, swap_max, swap_fee, fee_policy)
+        }
+        TimelockedOperation::GrantRole { role, address } => {
+            try_grant_role(deps, info, role, address)
+        }
+        TimelockedOperation::RevokeRole { role, address } => {
+            try_revoke_role(deps, info, role, address)
+        }
+        TimelockedOperation::ProposeAdmin {
+            address,
+            expires_at_block,
+        } => try_propose_admin(deps, env, info, proposer, address, expires_at_block),
+        TimelockedOperation::UnpausePublicApi { since_block, asset } => {
+            verify_unpause_not_stale(env, &PauseStart::Height(since_block))?;
+            try_pause_public_api(deps, env, info, since_block, asset)
+        }
+        TimelockedOperation::UnpauseRelayerApi { since_block, asset } => {
+            verify_unpause_not_stale(env, &PauseStart::Height(since_block))?;
+            try_pause_relayer_api(deps, env, info, since_block, asset)
+        }
+        TimelockedOperation::Unpause {
+            operation,
+            since,
+            reason,
+        } => {
+            verify_unpause_not_stale(env, &since)?;
+            try_pause(deps, env, info, operation, since, reason)
+        }
+        TimelockedOperation::SetTimelockDelay { blocks } => {
+            try_set_timelock_delay(deps, info, blocks)
+        }
+        TimelockedOperation::SetRelayerThreshold { threshold } => {
+            try_set_relayer_threshold(deps, info, threshold)
+        }
+    }
+}
+
+fn try_set_dust_policy(
+    deps: DepsMut,
+    info: &MessageInfo,
+    policy: DustPolicy,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    DUST_POLICY.save(deps.storage, &policy)?;
+
+    let attrs = vec![
+        attr("action", "set_dust_policy"),
+        attr("policy", dust_policy_name(&policy)),
+    ];
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_set_custody_mode(
+    deps: DepsMut,
+    info: &MessageInfo,
+    mode: CustodyMode,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    // the supply of native assets changes meaning with the mode
+    if mode != custody_mode(deps.storage)? {
+        for item in ASSETS.range(deps.storage, None, None, Order::Ascending) {
+            let (denom, asset) = item?;
+            if asset.kind == AssetKind::Native && !asset.supply.is_zero() {
+                return Err(ContractError::CustodyModeSupplyOutstanding {
+                    denom,
+                    supply: asset.supply,
+                });
+            }
+        }
+    }
+    CUSTODY_MODE.save(deps.storage, &mode)?;
+
+    let attrs = vec![
+        attr("action", "set_custody_mode"),
+        attr("mode", custody_mode_name(&mode)),
+    ];
+    Ok(Response::new().add_attributes(attrs))
+}
+
+// The default asset is moved to the created denom along with its limits, nothing must be
+// held or owed in the previous denom
+fn try_create_denom(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    mut state: State,
+    subdenom: String,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    let denom = tokenfactory_denom(env, &subdenom);
+    if ASSETS.has(deps.storage, &denom) {
+        return Err(ContractError::AssetAlreadyExists { denom });
+    }
+    let mut asset = ASSETS.load(deps.storage, &state.denom)?;
+    if !asset.supply.is_zero() || !asset.fees_accrued.is_zero() {
+        return Err(ContractError::DenomSupplyOutstanding { denom: asset.denom });
+    }
+
+    rekey_default_asset(deps.storage, &mut state, &mut asset, denom.clone())?;
+    ASSETS.save(deps.storage, &denom, &asset)?;
+    CONFIG.save(deps.storage, &state)?;
+
+    let dialect = tokenfactory_dialect(deps.storage)?;
+    let msg = create_denom(env, dialect, subdenom.clone())?;
+    let attrs = vec![
+        attr("action", "create_denom"),
+        attr("subdenom", subdenom),
+        attr("denom", denom),
+    ];
+
+    Ok(Response::new().add_attributes(attrs).add_message(msg))
+}
+
+// Moves the default asset, its limits and their usage to `denom`. Claimable and held
+// payouts name their denom, so the asset can't move while any are outstanding in it.
+pub(crate) fn rekey_default_asset(
+    storage: &mut dyn Storage,
+    state: &mut State,
+    asset: &mut Asset,
+    denom: String,
+) -> Result<(), ContractError> {
+    if ASSETS.has(storage, &denom) {
+        return Err(ContractError::AssetAlreadyExists { denom });
+    }
+    let old_denom = asset.denom.clone();
+    let claimable = CLAIMABLE
+        .keys(storage, None, None, Order::Ascending)
+        .map(|key| key.map(|(_, claimable_denom)| claimable_denom))
+        .collect::<StdResult<Vec<_>>>()?;
+    let held = HELD_PAYOUTS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, payout)| payout.denom))
+        .collect::<StdResult<Vec<_>>>()?;
+    if claimable.contains(&old_denom) || held.contains(&old_denom) {
+        return Err(ContractError::DenomBalancesOutstanding { denom: old_denom });
+    }
+
+    ASSETS.remove(storage, &old_denom);
+    if let Some(limit) = OUTFLOW_LIMIT.may_load(storage, &old_denom)? {
+        OUTFLOW_LIMIT.remove(storage, &old_denom);
+        OUTFLOW_LIMIT.save(storage, &denom, &limit)?;
+    }
+    if let Some(usage) = OUTFLOW_USAGE.may_load(storage, &old_denom)? {
+        OUTFLOW_USAGE.remove(storage, &old_denom);
+        OUTFLOW_USAGE.save(storage, &denom, &usage)?;
+    }
+    if let Some(limits) = SENDER_LIMITS.may_load(storage, &old_denom)? {
+        SENDER_LIMITS.remove(storage, &old_denom);
+        SENDER_LIMITS.save(storage, &denom, &limits)?;
+    }
+    let sender_usage = SENDER_USAGE
+        .prefix(&old_denom)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (sender, usage) in sender_usage {
+        SENDER_USAGE.remove(storage, (&old_denom, &sender));
+        SENDER_USAGE.save(storage, (&denom, &sender), &usage)?;
+    }
+
+    // refunds of the swaps made in the old denom are paid in the new one
+    DENOM_RENAMES.remove(storage, &denom);
+    DENOM_RENAMES.save(storage, &old_denom, &denom)?;
+    asset.denom = denom.clone();
+    state.denom = denom;
+    Ok(())
+}
+
+// The new admin could mint the issued token at will, and the contract could no longer mint
+// what it owes, so the admin stays while any of it is outstanding
+fn try_change_denom_admin(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    asset: Asset,
+    new_admin: Addr,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+    verify_asset_kind(&asset, AssetKind::Native)?;
+    if custody_mode(deps.storage)? == CustodyMode::MintBurn && !asset.supply.is_zero() {
+        return Err(ContractError::DenomAdminSupplyOutstanding {
+            denom: asset.denom,
+            supply: asset.supply,
+        });
+    }
+
+    let dialect = tokenfactory_dialect(deps.storage)?;
+    let msg = change_denom_admin(env, dialect, asset.denom.clone(), new_admin.to_string())?;
+    let attrs = vec![
+        attr("action", "change_denom_admin"),
+        attr("denom", asset.denom),
+        attr("new_admin", new_admin),
+    ];
+
+    Ok(Response::new().add_attributes(attrs).add_message(msg))
+}
+
+fn try_set_denom_metadata(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    asset: Asset,
+    metadata: DenomMetadata,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+    verify_asset_kind(&asset, AssetKind::Native)?;
+
+    let dialect = tokenfactory_dialect(deps.storage)?;
+    let msg = set_denom_metadata(env, dialect, metadata)?;
+    let attrs = vec![
+        attr("action", "set_denom_metadata"),
+        attr("denom", asset.denom),
+    ];
+
+    Ok(Response::new().add_attributes(attrs).add_message(msg))
+}
+
+fn credit_claimable(
+    storage: &mut dyn Storage,
+    to: &Addr,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    CLAIMABLE.update(storage, (to, denom), |claimable| -> StdResult<_> {
+        Ok(claimable.unwrap_or_default() + amount)
+    })?;
+    Ok(())
+}
+
+fn try_set_payout_mode(
+    deps: DepsMut,
+    info: &MessageInfo,
+    mode: PayoutMode,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    PAYOUT_MODE.save(deps.storage, &mode)?;
+
+    let attrs = vec![
+        attr("action", "set_payout_mode"),
+        attr("mode", payout_mode_name(&mode)),
+    ];
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_redirect_claimable(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    asset: Asset,
+    address: Addr,
+    to: Addr,
+) -> Result<Response, ContractError> {
+    verify_not_paused_public_api(env, deps.storage)?;
+    if info.sender != address {
+        only_admin(info, deps.storage)?;
+    } else {
+        verify_address_allowed(deps.storage, address.as_str())?;
+        verify_address_allowed(deps.storage, to.as_str())?;
+    }
+
+    let amount = CLAIMABLE
+        .may_load(deps.storage, (&address, &asset.denom))?
+        .ok_or(ContractError::NothingToClaim {})?;
+    CLAIMABLE.remove(deps.storage, (&address, &asset.denom));
+
+    let minted = minting_dialect(deps.storage, &asset)?;
+    let msg = payout_msg(env, &asset, minted, &to, amount)?;
+    let attrs = vec![
+        attr("action", "redirect_claimable"),
+        attr("from", address),
+        attr("to", to),
+        attr("amount", amount),
+        attr("denom", asset.denom),
+    ];
+
+    Ok(Response::new().add_attributes(attrs).add_message(msg))
+}
+
+// the amounts held are always paid out to `address`, whoever claims them
+fn try_claim(deps: DepsMut, env: &Env, address: Addr) -> Result<Response, ContractError> {
+    verify_not_paused_public_api(env, deps.storage)?;
+    verify_address_allowed(deps.storage, address.as_str())?;
+
+    let claimable = CLAIMABLE
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<Vec<_>>>()?;
+    if claimable.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    for coin in &claimable {
+        CLAIMABLE.remove(deps.storage, (&address, &coin.denom));
+    }
+
+    let mut msgs = vec![];
+    for coin in &claimable {
+        let asset = load_known_asset(deps.storage, &coin.denom)?;
+        let minted = minting_dialect(deps.storage, &asset)?;
+        msgs.push(payout_msg(env, &asset, minted, &address, coin.amount)?);
+    }
+
+    let attrs = vec![
+        attr("action", "claim"),
+        attr("to", address.as_str()),
+        attr(
+            "amount",
+            claimable
+                .iter()
+                .map(Coin::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+    ];
+
+    Ok(Response::new().add_attributes(attrs).add_messages(msgs))
+}
+
+fn try_set_sender_limits(
+    deps: DepsMut,
+    info: &MessageInfo,
+    asset: &Asset,
+    limits: Option<SenderLimits>,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    let mut attrs = vec![
+        attr("action", "set_sender_limits"),
+        attr("denom", asset.denom.as_str()),
+    ];
+    match limits {
+        None => {
+            SENDER_LIMITS.remove(deps.storage, &asset.denom);
+            attrs.push(attr("window", "none"));
+        }
+        Some(limits) => {
+            if limits.window.period() == 0 {
+                return Err(ContractError::SenderLimitsInvalid {});
+            }
+            attrs.push(attr("window", rate_window_name(&limits.window)));
+            if let Some(max_volume) = limits.max_volume {
+                attrs.push(attr("max_volume", max_volume));
+            }
+            if let Some(max_swaps) = limits.max_swaps {
+                attrs.push(attr("max_swaps", max_swaps.to_string()));
+            }
+            SENDER_LIMITS.save(deps.storage, &asset.denom, &limits)?;
+        }
+    }
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_set_outflow_limit(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    asset: &Asset,
+    limit: Option<OutflowLimit>,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    let denom = asset.denom.as_str();
+    let limit = match limit {
+        None => {
+            OUTFLOW_LIMIT.remove(deps.storage, denom);
+            OUTFLOW_USAGE.remove(deps.storage, denom);
+            return Ok(Response::new().add_attributes(vec![
+                attr("action", "set_outflow_limit"),
+                attr("denom", denom),
+                attr("max_outflow", "none"),
+            ]));
+        }
+        Some(limit) => limit,
+    };
+    if limit.period() == 0 {
+        return Err(ContractError::OutflowLimitInvalid {});
+    }
+
+    // carry over what was already consumed under the previous limit
+    let usage = match OUTFLOW_LIMIT.may_load(deps.storage, denom)? {
+        Some(previous) => {
+            let usage = OUTFLOW_USAGE.may_load(deps.storage, denom)?;
+            let available = previous.available(usage.as_ref(), previous.now(env));
+            Some(OutflowUsage {
+                available: available.min(limit.max_outflow),
+                updated_at: limit.now(env),
+            })
+        }
+        None => None,
+    };
+    OUTFLOW_LIMIT.save(deps.storage, denom, &limit)?;
+    match usage {
+        Some(usage) => OUTFLOW_USAGE.save(deps.storage, denom, &usage)?,
+        None => OUTFLOW_USAGE.remove(deps.storage, denom),
+    }
+
+    let attrs = vec![
+        attr("action", "set_outflow_limit"),
+        attr("denom", denom),
+        attr("max_outflow", limit.max_outflow),
+        attr("window", rate_window_name(&limit.window)),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_set_relayer_threshold(
+    deps: DepsMut,
+    info: &MessageInfo,
+    threshold: u32,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    if threshold == 0 {
+        return Err(ContractError::InvalidRelayerThreshold {});
+    }
+    RELAYER_THRESHOLD.save(deps.storage, &threshold)?;
+
+    let attrs = vec![
+        attr("action", "set_relayer_threshold"),
+        attr("threshold", threshold.to_string()),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_clear_attestations(
+    deps: DepsMut,
+    info: &MessageInfo,
+    kind: AttestationKind,
+    id: u64,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    ATTESTATIONS.remove(deps.storage, (kind.as_str(), id));
+
+    let attrs = vec![
+        attr("action", "clear_attestations"),
+        attr("kind", kind.as_str()),
+        attr("id", id.to_string()),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_set_timelock_delay(
+    deps: DepsMut,
+    info: &MessageInfo,
+    blocks: u64,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    TIMELOCK_DELAY.save(deps.storage, &blocks)?;
+
+    let attrs = vec![
+        attr("action", "set_timelock_delay"),
+        attr("blocks", blocks.to_string()),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_execute_queued(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    let queued = QUEUED_OPERATIONS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::TimelockUnknownOperation { id })?;
+    if env.block.height < queued.execute_at_block {
+        return Err(ContractError::TimelockNotReady {
+            id,
+            execute_at_block: queued.execute_at_block,
+        });
+    }
+    QUEUED_OPERATIONS.remove(deps.storage, id);
+
+    let response = run_operation(deps, env, info, &queued.proposer, queued.operation)?;
+    Ok(response.add_attribute("queued_operation_id", id.to_string()))
+}
+
+fn try_cancel_queued(
+    deps: DepsMut,
+    info: &MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    only_monitor(info, deps.storage).or_else(|_| only_admin(info, deps.storage))?;
+
+    if !QUEUED_OPERATIONS.has(deps.storage, id) {
+        return Err(ContractError::TimelockUnknownOperation { id });
+    }
+    QUEUED_OPERATIONS.remove(deps.storage, id);
+
+    let attrs = vec![
+        attr("action", "cancel_queued"),
+        attr("id", id.to_string()),
+        attr("sender", info.sender.as_str()),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn try_set_reverse_swap_id_ordering(
+    deps: DepsMut,
+    info: &MessageInfo,
+    ordering: ReverseSwapIdOrdering,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    REVERSE_SWAP_ID_ORDERING.save(deps.storage, &ordering)?;
+
+    let ordering_name = match ordering {
+        ReverseSwapIdOrdering::Unordered => "unordered".to_string(),
+        ReverseSwapIdOrdering::StrictlyIncreasing => "strictly_increasing".to_string(),
+        ReverseSwapIdOrdering::Contiguous { max_gap } => format!("contiguous({})", max_gap),
+    };
+    let attrs = vec![
+        attr("action", "set_reverse_swap_id_ordering"),
+        attr("ordering", ordering_name),
+    ];
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/* ***************************************************
+ * *****************    Helpers      *****************
+ * ***************************************************/
+
+// Tallies the sender's attestation of the transfer `id`, returns the response to send
+// back as long as the relayer threshold of identical attestations isn't reached
+fn attest(
+    storage: &mut dyn Storage,
+    info: &MessageInfo,
+    kind: AttestationKind,
+    id: u64,
+    attestation: Attestation,
+) -> Result<Option<Response>, ContractError> {
+    let threshold = relayer_threshold(storage)?;
+    if threshold > 1 {
+        let key = (kind.as_str(), id);
+        let mut attestations = ATTESTATIONS.may_load(storage, key)?.unwrap_or_default();
+        // attestations of a previous eon can't be completed anymore
+        attestations.retain(|a| a.relay_eon == attestation.relay_eon);
+        if attestations
+            .iter()
+            .any(|a| a.relayers.contains(&info.sender))
+        {
+            return Err(ContractError::AlreadyAttested { id });
+        }
+
+        let index = match attestations
+            .iter()
+            .position(|a| a.same_transfer(&attestation))
+        {
+            Some(index) => index,
+            None => {
+                attestations.push(attestation);
+                attestations.len() - 1
+            }
+        };
+        attestations[index].relayers.push(info.sender.clone());
+
+        // relayers revoked in the meantime don't count
+        let mut tally = 0u32;
+        for relayer in &attestations[index].relayers {
+            if ac_have_role(storage, relayer, &AccessRole::Relayer)? {
+                tally += 1;
+            }
+        }
+
+        if tally < threshold {
+            ATTESTATIONS.save(storage, key, &attestations)?;
+
+            let attrs = vec![
+                attr("action", format!("attest_{}", kind.as_str())),
+                attr("id", id.to_string()),
+                attr("relayer", info.sender.as_str()),
+                attr("attestations", tally.to_string()),
+                attr("relayer_threshold", threshold.to_string()),
+            ];
+            return Ok(Some(Response::new().add_attributes(attrs)));
+        }
+    }
+
+    // a swap is settled by either kind of refund
+    let settled: &[AttestationKind] = match kind {
+        AttestationKind::ReverseSwap => &[AttestationKind::ReverseSwap],
+        AttestationKind::Refund | AttestationKind::RefundInFull => {
+            &[AttestationKind::Refund, AttestationKind::RefundInFull]
+        }
+    };
+    for kind in settled {
+        ATTESTATIONS.remove(storage, (kind.as_str(), id));
+    }
+    Ok(None)
+}
+
+// Returns the new sealed reverse swap id once `rid` is processed,
+// recording the skipped ids (if any) when an ordering policy is enforced
+fn seal_reverse_swap_id(storage: &mut dyn Storage, state: &State, rid: u64) -> StdResult<u64> {
+    let sealed = state.sealed_reverse_swap_id;
+    if rid < sealed {
+        // only reachable with unordered policy
+        return Ok(sealed);
+    }
+
+    if rid > sealed && reverse_swap_id_ordering(storage)? != ReverseSwapIdOrdering::Unordered {
+        let gap = ReverseSwapIdGap {
+            start: sealed,
+            end: rid,
+            relay_eon: state.relay_eon,
+        };
+        REVERSE_SWAP_ID_GAPS.save(storage, sealed, &gap)?;
+    }
+
+    Ok(rid.saturating_add(1))
+}
+
+// Asset named by a message, the default asset when none is named
+fn load_asset(
+    storage: &dyn Storage,
+    state: &State,
+    asset: Option<String>,
+) -> Result<Asset, ContractError> {
+    load_known_asset(storage, asset.as_deref().unwrap_or(&state.denom))
+}
+
+fn load_known_asset(storage: &dyn Storage, denom: &str) -> Result<Asset, ContractError> {
+    ASSETS
+        .may_load(storage, denom)?
+        .ok_or_else(|| ContractError::UnknownAsset {
+            denom: denom.to_string(),
+        })
+}
+
+// A refund pays out the asset of the refunded swap, `asset` may only confirm it
+fn load_refund_asset(
+    storage: &dyn Storage,
+    state: &State,
+    id: u64,
+    asset: Option<String>,
+) -> Result<Asset, ContractError> {
+    // NOTE swaps made before swap records were introduced are in the default asset
+    let swap_denom = match SWAPS.may_load(storage, id)? {
+        Some(swap) => swap.denom,
+        None => return load_asset(storage, state, asset),
+    };
+    if let Some(denom) = asset
+        && denom != swap_denom
+    {
+        return Err(ContractError::AssetMismatch {
+            expected: swap_denom,
+            denom,
+        });
+    }
+    load_known_asset(storage, &renamed_denom(storage, &swap_denom)?)
+}
+
+pub fn amount_from_funds(funds: &[Coin], denom: String) -> Result<Uint128, ContractError> {
+    for coin in funds {
+        if coin.denom == denom {
+            return Ok(coin.amount);
+        }
+    }
+    Err(ContractError::UnrecognizedDenom { denom })
+}
+
+fn send_tokens_from_contract(
+    api: &dyn Api,
+    env: &Env,
+    asset: &Asset,
+    minted: Option<TokenfactoryDialect>,
+    to_address: &CanonicalAddr,
+    amount: Uint128,
+    action: &str,
+) -> Result<Response, ContractError> {
+    let to_human = api.addr_humanize(to_address)?;
+    let attrs = vec![attr("action", action), attr("to", to_human.as_str())];
+    let msg = payout_msg(env, asset, minted, &to_human, amount)?;
+    Ok(Response::new().add_attributes(attrs).add_message(msg))
+}
+
+// Same as `send_tokens_from_contract`, the payout of the operation `id` is credited
+// to the recipient if it fails (see `reply_payout`) or in pull payout mode
+#[allow(clippy::too_many_arguments)]
+fn send_payout(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    env: &Env,
+    asset: &Asset,
+    minted: Option<TokenfactoryDialect>,
+    to_address: &CanonicalAddr,
+    amount: Uint128,
+    action: &str,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let to_human = api.addr_humanize(to_address)?;
+    let attrs = vec![attr("action", action), attr("to", to_human.as_str())];
+
+    if payout_mode(storage)? == PayoutMode::Pull {
+        credit_claimable(storage, &to_human, &asset.denom, amount)?;
+        let event = Event::new("payout_credited").add_attributes(vec![
+            attr("operation", action),
+            attr("id", id.to_string()),
+            attr("to", to_human.as_str()),
+            attr("amount", amount),
+            attr("denom", &asset.denom),
+        ]);
+        return Ok(Response::new().add_attributes(attrs).add_event(event));
+    }
+
+    let msg = payout_msg(env, asset, minted, &to_human, amount)?;
+    let payout = Payout {
+        operation: action.to_string(),
+        id,
+        to: to_human,
+        denom: asset.denom.clone(),
+        amount,
+    };
+    let submsg =
+        SubMsg::reply_on_error(msg, PAYOUT_REPLY_ID).with_payload(to_json_binary(&payout)?);
+    Ok(Response::new().add_attributes(attrs).add_submessage(submsg))
+}
+
+// Payouts to addresses not allowed are held until an admin releases them
+fn hold_payout(storage: &mut dyn Storage, payout: Payout) -> StdResult<Event> {
+    HELD_PAYOUTS.save(storage, (&payout.operation, payout.id), &payout)?;
+
+    let event = Event::new("payout_held").add_attributes(vec![
+        attr("operation", payout.operation),
+        attr("id", payout.id.to_string()),
+        attr("to", payout.to),
+        attr("amount", payout.amount),
+        attr("denom", payout.denom),
+    ]);
+    Ok(event)
+}
+
+// Amounts owed to the recipient of `payout` are credited to it to be claimed, or held
+// like its payouts when it isn't allowed
+fn credit_or_hold(storage: &mut dyn Storage, payout: Payout) -> StdResult<Option<Event>> {
+    if address_allowed(storage, payout.to.as_str())? {
+        credit_claimable(storage, &payout.to, &payout.denom, payout.amount)?;
+        return Ok(None);
+    }
+    hold_payout(storage, payout).map(Some)
+}
+
+fn try_release_held_payout(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    operation: String,
+    id: u64,
+    to: Option<Addr>,
+) -> Result<Response, ContractError> {
+    only_admin(info, deps.storage)?;
+
+    let payout = HELD_PAYOUTS
+        .may_load(deps.storage, (&operation, id))?
+        .ok_or(ContractError::HeldPayoutNotFound { operation, id })?;
+    HELD_PAYOUTS.remove(deps.storage, (&payout.operation, id));
+
+    let to = to.unwrap_or(payout.to);
+    let asset = load_known_asset(deps.storage, &payout.denom)?;
+    let minted = minting_dialect(deps.storage, &asset)?;
+    let to_canonical = deps.api.addr_canonicalize(to.as_str())?;
+    let rtx = send_payout(
+        deps.api,
+        deps.storage,
+        env,
+        &asset,
+        minted,
+        &to_canonical,
+        payout.amount,
+        &payout.operation,
+        id,
+    )?;
+
+    let attrs = vec![
+        attr("action", "release_held_payout"),
+        attr("operation", &payout.operation),
+        attr("id", id.to_string()),
+        attr("to", to),
+        attr("amount", payout.amount),
+        attr("denom", payout.denom),
+    ];
+    Ok(Response::new()
+        .add_attributes(attrs)
+        .add_submessages(rtx.messages)
+        .add_events(rtx.events))
+}
+
+// Payout of `amount` of the asset to `to`, minted when the asset is `minted`
+fn payout_msg(
+    env: &Env,
+    asset: &Asset,
+    minted: Option<TokenfactoryDialect>,
+    to: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    match minted {
+        Some(dialect) => {
+            mint_tokens_to_address(env, dialect, asset.denom.clone(), amount, to.to_string())
+        }
+        None => transfer_msg(asset, to, amount),
+    }
+}
+
+// Native assets are burnt and minted by the contract in mint/burn custody mode,
+// the dialect of the chain's tokenfactory is returned for those
+fn minting_dialect(storage: &dyn Storage, asset: &Asset) -> StdResult<Option<TokenfactoryDialect>> {
+    if asset.kind == AssetKind::Native && custody_mode(storage)? == CustodyMode::MintBurn {
+        return Ok(Some(tokenfactory_dialect(storage)?));
+    }
+    Ok(None)
+}
+
+// Supply of the asset once `amount` is swapped in
+fn supply_in(asset: &Asset, amount: Uint128, minted: bool) -> Result<Uint128, ContractError> {
+    if minted {
+        // burnt, no longer issued
+        asset
+            .supply
+            .checked_sub(amount)
+            .map_err(|_| ContractError::SupplyExceeded {
+                requested: amount,
+                available: asset.supply,
+            })
+    } else {
+        let increased_supply = asset.supply + amount;
+        if increased_supply > asset.cap {
+            return Err(ContractError::CapExceeded {
+                supply: increased_supply,
+                cap: asset.cap,
+            });
+        }
+        Ok(increased_supply)
+    }
+}
+
+// Supply of the asset once `amount` (fee included) is paid out
+fn supply_out(asset: &Asset, amount: Uint128, minted: bool) -> Result<Uint128, ContractError> {
+    if minted {
+        let increased_supply = asset.supply + amount;
+        if increased_supply > asset.cap {
+            return Err(ContractError::CapExceeded {
+                supply: increased_supply,
+                cap: asset.cap,
+            });
+        }
+        Ok(increased_supply)
+    } else {
+        asset
+            .supply
+            .checked_sub(amount)
+            .map_err(|_| ContractError::SupplyExceeded {
+                requested: amount,
+                available: asset.supply,
+            })
+    }
+}
+
+// Transfer of `amount` of the asset from the contract to `to`
+fn transfer_msg(asset: &Asset, to: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+    Ok(match asset.kind {
+        AssetKind::Native => CosmosMsg::Bank(BankMsg::Send {
+            to_address: to.to_string(),
+            amount: vec![Coin {
+                amount,
+                denom: asset.denom.clone(),
+            }],
+        }),
+        AssetKind::Cw20 => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: asset.denom.clone(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: to.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }),
+    })
+}
+
+// Native tokens of the asset sent along the message
+fn native_amount(funds: &[Coin], asset: &Asset) -> Result<Uint128, ContractError> {
+    verify_asset_kind(asset, AssetKind::Native)?;
+    amount_from_funds(funds, asset.denom.clone())
+}
+
+/* ***************************************************
+ * ***************    Verifiers      *****************
+ * ***************************************************/
+
+fn verify_asset_kind(asset: &Asset, kind: AssetKind) -> Result<(), ContractError> {
+    if asset.kind != kind {
+        return Err(ContractError::AssetKindMismatch {
+            denom: asset.denom.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn verify_dust_policy(
+    amount: Uint128,
+    fee: Uint128,
+    storage: &dyn Storage,
+) -> Result<DustPolicy, ContractError> {
+    let policy = dust_policy(storage)?;
+    if amount <= fee && policy == DustPolicy::Reject {
+        return Err(ContractError::DustRejected { amount, fee });
+    }
+    Ok(policy)
+}
+
+fn verify_tx_relay_eon(eon: u64, state: &State) -> Result<Response, ContractError> {
+    if eon != state.relay_eon {
+        Err(ContractError::Eon {
+            tx_eon: eon,
+            current_eon: state.relay_eon,
+        })
+    } else {
+        Ok(Response::default())
+    }
+}
+
+// for the messages outside of the operations of an API, paused along with all of them
+pub fn verify_not_paused_public_api(
+    env: &Env,
+    storage: &dyn Storage,
+) -> Result<Response, ContractError> {
+    let since_block = api_paused_since_block(storage, env, &PausableOperation::PUBLIC_API)?;
+    _verify_not_paused(env, since_block)
+}
+
+pub fn verify_not_paused_relayer_api(
+    env: &Env,
+    storage: &dyn Storage,
+) -> Result<Response, ContractError> {
+    let since_block = api_paused_since_block(storage, env, &PausableOperation::RELAYER_API)?;
+    _verify_not_paused(env, since_block)
+}
+
+pub fn verify_address_allowed(storage: &dyn Storage, address: &str) -> Result<(), ContractError> {
+    if !address_allowed(storage, address)? {
+        return Err(ContractError::AddressBlocked {
+            address: address.to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn verify_not_paused_operation(
+    env: &Env,
+    storage: &dyn Storage,
+    operation: PausableOperation,
+) -> Result<Response, ContractError> {
+    match PAUSES.may_load(storage, operation.name())? {
+        Some(pause) if pause.active(env) => Err(ContractError::OperationPaused {
+            since: pause.since.to_string(),
+            reason: pause.reason,
+        }),
+        _ => Ok(Response::default()),
+    }
+}
+
+fn _verify_not_paused(env: &Env, paused_since_block: u64) -> Result<Response, ContractError> {
+    if env.block.height < paused_since_block {
+        Ok(Response::default())
+    } else {
+        Err(ContractError::ContractPaused {
+            since_block: paused_since_block,
+        })
+    }
+}
+
+fn verify_swap_amount_limits(amount: Uint128, asset: &Asset) -> Result<Response, ContractError> {
+    if amount < asset.lower_swap_limit || amount > asset.upper_swap_limit {
+        Err(ContractError::SwapLimitsViolated {
+            amount,
+            min: asset.lower_swap_limit,
+            max: asset.upper_swap_limit,
+        })
+    } else {
+        Ok(Response::default())
+    }
+}
+
+fn verify_aggregated_reverse_allowance(
+    amount: Uint128,
+    asset: &Asset,
+) -> Result<Response, ContractError> {
+    if asset.reverse_aggregated_allowance < amount {
+        Err(ContractError::RaAllowanceExceeded {
+            requested: amount,
+            available: asset.reverse_aggregated_allowance,
+        })
+    } else {
+        Ok(Response::default())
+    }
+}
+
+// the fee charged is checked against the limits, `swap_fee` only when no policy is given
+fn verify_limits(
+    swap_min: Uint128,
+    swap_max: Uint128,
+    swap_fee: Uint128,
+    fee_policy: &Option<FeePolicy>,
+) -> Result<(), ContractError> {
+    match fee_policy {
+        Some(fee_policy) => fee_policy.verify_limits(swap_min, swap_max),
+        None => FeePolicy::Flat { fee: swap_fee }.verify_limits(swap_min, swap_max),
+    }
+}
+
+fn fee_policy_name(fee_policy: &FeePolicy) -> String {
+    match fee_policy {
+        FeePolicy::Flat { fee } => format!("flat {}", fee),
+        FeePolicy::BasisPoints { bps, .. } => format!("{} bps", bps),
+        FeePolicy::Tiered { tiers } => format!("{} tiers", tiers.len()),
+    }
+}
+
+fn custody_mode_name(mode: &CustodyMode) -> &'static str {
+    match mode {
+        CustodyMode::Lock => "lock",
+        CustodyMode::MintBurn => "mint_burn",
+    }
+}
+
+fn payout_mode_name(mode: &PayoutMode) -> &'static str {
+    match mode {
+        PayoutMode::Push => "push",
+        PayoutMode::Pull => "pull",
+    }
+}
+
+fn dust_policy_name(policy: &DustPolicy) -> &'static str {
+    match policy {
+        DustPolicy::Reject => "reject",
+        DustPolicy::Hold => "hold",
+        DustPolicy::Confiscate => "confiscate",
+    }
+}
+
+// Settles an `amount` not exceeding its `fee`, returns the fee actually taken
+// and the events recording the outcome
+#[allow(clippy::too_many_arguments)]
+fn settle_dust(
+    storage: &mut dyn Storage,
+    policy: &DustPolicy,
+    operation: &str,
+    id: u64,
+    denom: &str,
+    to: &Addr,
+    amount: Uint128,
+    fee: Uint128,
+) -> Result<(Uint128, Vec<Event>), ContractError> {
+    let mut events = vec![];
+    let (fee_taken, event) = match policy {
+        DustPolicy::Hold => {
+            let payout = Payout {
+                operation: operation.to_string(),
+                id,
+                to: to.clone(),
+                denom: denom.to_string(),
+                amount,
+            };
+            events.extend(credit_or_hold(storage, payout)?);
+            (Uint128::zero(), Event::new("dust_held"))
+        }
+        // rejected dust never gets there
+        DustPolicy::Reject | DustPolicy::Confiscate => (amount, Event::new("dust_confiscated")),
+    };
+
+    let event = event.add_attributes(vec![
+        attr("operation", operation),
+        attr("id", id.to_string()),
+        attr("to", to.as_str()),
+        attr("amount", amount),
+        attr("fee", fee),
+        attr("denom", denom),
+    ]);
+    events.insert(0, event);
+    Ok((fee_taken, events))
+}
+
+fn rate_window_name(window: &RateWindow) -> String {
+    match window {
+        RateWindow::Blocks(blocks) => format!("{} blocks", blocks),
+        RateWindow::Seconds(seconds) => format!("{} seconds", seconds),
+    }
+}
+
+// Accounts the swap of `amount` by `sender` against the per sender limits (if any)
+fn track_sender_usage(
+    env: &Env,
+    denom: &str,
+    sender: &Addr,
+    amount: Uint128,
+    storage: &mut dyn Storage,
+) -> Result<(), ContractError> {
+    let limits = match SENDER_LIMITS.may_load(storage, denom)? {
+        Some(limits) => limits,
+        None => return Ok(()),
+    };
+    let mut usage = sender_usage(storage, denom, &limits, sender, limits.window.now(env))?;
+
+    if let Some(max_swaps) = limits.max_swaps
+        && usage.swaps >= max_swaps
+    {
+        return Err(ContractError::SenderSwapCountExceeded { max_swaps });
+    }
+    if let Some(max_volume) = limits.max_volume
+        && usage.volume + amount > max_volume
+    {
+        return Err(ContractError::SenderVolumeExceeded {
+            requested: amount,
+            available: max_volume.saturating_sub(usage.volume),
+        });
+    }
+
+    usage.volume += amount;
+    usage.swaps += 1;
+    SENDER_USAGE.save(storage, (denom, sender), &usage)?;
+    Ok(())
+}
+
+fn verify_outflow_limit(
+    env: &Env,
+    denom: &str,
+    amount: Uint128,
+    storage: &dyn Storage,
+) -> Result<Response, ContractError> {
+    if let Some(limit) = OUTFLOW_LIMIT.may_load(storage, denom)? {
+        let usage = OUTFLOW_USAGE.may_load(storage, denom)?;
+        let available = limit.available(usage.as_ref(), limit.now(env));
+        if available < amount {
+            return Err(ContractError::OutflowLimitExceeded {
+                requested: amount,
+                available,
+            });
+        }
+    }
+    Ok(Response::default())
+}
+
+fn consume_outflow(
+    env: &Env,
+    denom: &str,
+    amount: Uint128,
+    storage: &mut dyn Storage,
+) -> StdResult<()> {
+    if let Some(limit) = OUTFLOW_LIMIT.may_load(storage, denom)? {
+        let usage = OUTFLOW_USAGE.may_load(storage, denom)?;
+        let now = limit.now(env);
+        let usage = OutflowUsage {
+            available: limit.available(usage.as_ref(), now).checked_sub(amount)?,
+            updated_at: now,
+        };
+        OUTFLOW_USAGE.save(storage, denom, &usage)?;
+    }
+    Ok(())
+}
+
+fn verify_refund_swap_id(id: u64, storage: &dyn Storage) -> Result<Response, ContractError> {
+    let state = CONFIG.load(storage)?;
+    if id >= state.next_swap_id {
+        // FIXME(LR) >= ?
+        return Err(ContractError::InvalidSwapId { id });
+    }
+    match refunds_have(id, storage) {
+        true => Err(ContractError::AlreadyRefunded { id }),
+        false => Ok(Response::default()),
+    }
+}
+
+fn verify_refund_amount(
+    id: u64,
+    amount: Uint128,
+    in_full: bool,
+    storage: &dyn Storage,
+) -> Result<Response, ContractError> {
+    // NOTE swaps made before swap records were introduced can't be verified
+    let swap = match SWAPS.may_load(storage, id)? {
+        Some(swap) => swap,
+        None => return Ok(Response::default()),
+    };
+    if in_full && amount != swap.amount {
+        Err(ContractError::RefundAmountNotFull {
+            amount,
+            swap_amount: swap.amount,
+        })
+    } else if amount > swap.amount {
+        Err(ContractError::RefundAmountExceeded {
+            amount,
+            swap_amount: swap.amount,
+        })
+    } else {
+        Ok(Response::default())
+    }
+}
+
+fn verify_reverse_swap_id(rid: u64, storage: &dyn Storage) -> Result<Response, ContractError> {
+    match reverse_swaps_have(rid, storage) {
+        true => Err(ContractError::AlreadyReverseSwapped { rid }),
+        false => Ok(Response::default()),
+    }
+}
+
+fn verify_reverse_swap_id_order(
+    rid: u64,
+    state: &State,
+    storage: &dyn Storage,
+) -> Result<Response, ContractError> {
+    let sealed = state.sealed_reverse_swap_id;
+    match reverse_swap_id_ordering(storage)? {
+        ReverseSwapIdOrdering::Unordered => Ok(Response::default()),
+        ReverseSwapIdOrdering::StrictlyIncreasing => {
+            if rid < sealed {
+                Err(ContractError::ReverseSwapIdOutOfOrder { rid, sealed })
+            } else {
+                Ok(Response::default())
+            }
+        }
+        ReverseSwapIdOrdering::Contiguous { max_gap } => {
+            if rid < sealed {
+                Err(ContractError::ReverseSwapIdOutOfOrder { rid, sealed })
+            } else if rid - sealed > max_gap {
+                Err(ContractError::ReverseSwapIdGapExceeded {
+                    rid,
+                    sealed,
+                    max_gap,
+                })
+            } else {
+                Ok(Response::default())
+            }
+        }
+    }
+}
+
+/* ***************************************************
+ * ************    Access Control      ***************
+ * ***************************************************/
+
+fn only_admin(info: &MessageInfo, storage: &dyn Storage) -> Result<Response, ContractError> {
+    _only_role(&AccessRole::Admin, info, storage)
+}
+
+fn only_relayer(info: &MessageInfo, storage: &dyn Storage) -> Result<Response, ContractError> {
+    _only_role(&AccessRole::Relayer, info, storage)
+}
+
+fn only_approver(info: &MessageInfo, storage: &dyn Storage) -> Result<Response, ContractError> {
+    _only_role(&AccessRole::Approver, info, storage)
+}
+
+fn only_monitor(info: &MessageInfo, storage: &dyn Storage) -> Result<Response, ContractError> {
+    _only_role(&AccessRole::Monitor, info, storage)
+}
+
+fn _only_role(
+    role: &AccessRole,
+    info: &MessageInfo,
+    storage: &dyn Storage,
+) -> Result<Response, ContractError> {
+    let env_message_sender = &info.sender;
+
+    match ac_have_role(storage, env_message_sender, role) {
+        Ok(has_role) => match has_role {
+            true => Ok(Response::default()),
+            false => Err(match role {
+                AccessRole::Admin => ContractError::OnlyAdmin {},
+                AccessRole::Relayer => ContractError::OnlyRelayer {},
+                _ => ContractError::OnlyAdmin {},
+            }),
+        },
+        Err(err) => Err(err.into()),
+    }
+}
+
+// a queued unpause executed past its block would pause the api instead
+fn verify_unpause_not_stale(env: &Env, since: &PauseStart) -> Result<(), ContractError> {
+    if since.reached(env) {
+        return Err(ContractError::TimelockStaleUnpause {
+            since: since.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn can_pause(
+    env: &Env,
+    info: &MessageInfo,
+    storage: &dyn Storage,
+    since_block: u64,
+) -> Result<Response, ContractError> {
+    if since_block > env.block.height {
+        // unpausing
         only_admin(info, storage)
     } else {
         // pausing
@@ -924,36 +3060,482 @@ fn can_pause(
  * *****************    Queries      *****************
  * ***************************************************/
 #[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
     let state = CONFIG.load(deps.storage)?;
     match msg {
         QueryMsg::HasRole { role, address } => to_json_binary(&query_role(deps, role, address)?),
         QueryMsg::RelayEon {} => to_json_binary(&RelayEonResponse {
             eon: state.relay_eon,
         }),
-        QueryMsg::Supply {} => to_json_binary(&SupplyResponse {
-            amount: state.supply,
+        QueryMsg::Supply { asset } => to_json_binary(&SupplyResponse {
+            amount: query_asset(deps, &state, asset)?.supply,
         }),
-        QueryMsg::Cap {} => to_json_binary(&CapResponse { amount: state.cap }),
-        QueryMsg::SwapMax {} => to_json_binary(&SwapMaxResponse {
-            amount: state.upper_swap_limit,
+        QueryMsg::Cap { asset } => to_json_binary(&CapResponse {
+            amount: query_asset(deps, &state, asset)?.cap,
         }),
-        QueryMsg::ReverseAggregatedAllowance {} => {
+        QueryMsg::SwapMax { asset } => to_json_binary(&SwapMaxResponse {
+            amount: query_asset(deps, &state, asset)?.upper_swap_limit,
+        }),
+        QueryMsg::ReverseAggregatedAllowance { asset } => {
             to_json_binary(&ReverseAggregatedAllowanceResponse {
-                amount: state.reverse_aggregated_allowance,
+                amount: query_asset(deps, &state, asset)?.reverse_aggregated_allowance,
             })
         }
-        QueryMsg::PausedPublicApiSince {} => to_json_binary(&PausedSinceBlockResponse {
-            block: state.paused_since_block_public_api,
+        QueryMsg::PausedPublicApiSince { asset } => to_json_binary(&PausedSinceBlockResponse {
+            block: match asset {
+                None => api_paused_since_block(deps.storage, &env, &PausableOperation::PUBLIC_API)?,
+                Some(denom) => {
+                    ASSETS
+                        .load(deps.storage, &denom)?
+                        .paused_since_block_public_api
+                }
+            },
         }),
-        QueryMsg::PausedRelayerApiSince {} => to_json_binary(&PausedSinceBlockResponse {
-            block: state.paused_since_block_relayer_api,
+        QueryMsg::PausedRelayerApiSince { asset } => to_json_binary(&PausedSinceBlockResponse {
+            block: match asset {
+                None => {
+                    api_paused_since_block(deps.storage, &env, &PausableOperation::RELAYER_API)?
+                }
+                Some(denom) => {
+                    ASSETS
+                        .load(deps.storage, &denom)?
+                        .paused_since_block_relayer_api
+                }
+            },
         }),
+        QueryMsg::PauseStatus { asset } => to_json_binary(&query_pause_status(deps, &env, asset)?),
         QueryMsg::Denom {} => to_json_binary(&DenomResponse { denom: state.denom }),
-        QueryMsg::FullState {} => to_json_binary(&state),
+        QueryMsg::FullState {} => to_json_binary(&query_full_state(deps, &env, state)?),
+        QueryMsg::Asset { denom } => {
+            to_json_binary::<AssetResponse>(&ASSETS.load(deps.storage, &denom)?)
+        }
+        QueryMsg::ListAssets { start_after, limit } => {
+            to_json_binary(&query_list_assets(deps, start_after, limit)?)
+        }
+        QueryMsg::ReverseSwapProcessed { rid } => to_json_binary(&ReverseSwapProcessedResponse {
+            processed: reverse_swaps_have(rid, deps.storage),
+        }),
+        QueryMsg::ReverseSwapIdSeal { start_after, limit } => to_json_binary(
+            &query_reverse_swap_id_seal(deps, &state, start_after, limit)?,
+        ),
+        QueryMsg::Refund { id } => to_json_binary(&query_refund(deps, id)?),
+        QueryMsg::Swap { id } => to_json_binary::<SwapResponse>(&SWAPS.load(deps.storage, id)?),
+        QueryMsg::ListSwaps {
+            start_after,
+            limit,
+            sender,
+        } => to_json_binary(&query_list_swaps(deps, start_after, limit, sender)?),
+        QueryMsg::ListRoleMembers {
+            role,
+            start_after,
+            limit,
+        } => to_json_binary(&query_list_role_members(deps, role, start_after, limit)?),
+        QueryMsg::RolesOf { address } => to_json_binary(&RolesOfResponse {
+            roles: ac_roles_of(deps.storage, &address)?,
+        }),
+        QueryMsg::PendingAdmin {} => to_json_binary(&PendingAdminResponse {
+            pending_admin: PENDING_ADMIN.may_load(deps.storage)?,
+        }),
+        QueryMsg::PendingOperations { start_after, limit } => {
+            to_json_binary(&query_pending_operations(deps, start_after, limit)?)
+        }
+        QueryMsg::SenderUsage { address, asset } => {
+            let asset = query_asset(deps, &state, asset)?;
+            to_json_binary(&query_sender_usage(deps, &env, &asset.denom, address)?)
+        }
+        QueryMsg::CustodyMode {} => to_json_binary(&CustodyModeResponse {
+            mode: custody_mode(deps.storage)?,
+        }),
+        QueryMsg::TokenfactoryDialect {} => to_json_binary(&TokenfactoryDialectResponse {
+            dialect: tokenfactory_dialect(deps.storage)?,
+        }),
+        QueryMsg::DustPolicy {} => to_json_binary(&DustPolicyResponse {
+            policy: dust_policy(deps.storage)?,
+        }),
+        QueryMsg::Claimable { address } => to_json_binary(&query_claimable(deps, address)?),
+        QueryMsg::ListClaimable { start_after, limit } => {
+            to_json_binary(&query_list_claimable(deps, start_after, limit)?)
+        }
+        QueryMsg::AddressList {
+            list,
+            start_after,
+            limit,
+        } => to_json_binary(&query_address_list(deps, list, start_after, limit)?),
+        QueryMsg::HeldPayouts { start_after, limit } => {
+            to_json_binary(&query_held_payouts(deps, start_after, limit)?)
+        }
+        QueryMsg::PayoutMode {} => to_json_binary(&PayoutModeResponse {
+            mode: payout_mode(deps.storage)?,
+        }),
+        QueryMsg::OutflowCapacity { asset } => {
+            let asset = query_asset(deps, &state, asset)?;
+            to_json_binary(&query_outflow_capacity(deps, &env, &asset.denom)?)
+        }
+        QueryMsg::PendingAttestations {
+            kind,
+            start_after,
+            limit,
+        } => to_json_binary(&query_pending_attestations(deps, kind, start_after, limit)?),
     }
 }
 
+fn query_asset(deps: Deps, state: &State, asset: Option<String>) -> StdResult<Asset> {
+    ASSETS.load(deps.storage, asset.as_deref().unwrap_or(&state.denom))
+}
+
+fn query_full_state(deps: Deps, env: &Env, state: State) -> StdResult<FullStateResponse> {
+    let asset = ASSETS.load(deps.storage, &state.denom)?;
+    Ok(FullStateResponse {
+        supply: asset.supply,
+        fees_accrued: asset.fees_accrued,
+        next_swap_id: state.next_swap_id,
+        sealed_reverse_swap_id: state.sealed_reverse_swap_id,
+        relay_eon: state.relay_eon,
+        upper_swap_limit: asset.upper_swap_limit,
+        lower_swap_limit: asset.lower_swap_limit,
+        reverse_aggregated_allowance: asset.reverse_aggregated_allowance,
+        reverse_aggregated_allowance_approver_cap: asset.reverse_aggregated_allowance_approver_cap,
+        cap: asset.cap,
+        swap_fee: asset.swap_fee,
+        paused_since_block_public_api: api_paused_since_block(
+            deps.storage,
+            env,
+            &PausableOperation::PUBLIC_API,
+        )?,
+        paused_since_block_relayer_api: api_paused_since_block(
+            deps.storage,
+            env,
+            &PausableOperation::RELAYER_API,
+        )?,
+        denom: state.denom,
+        contract_addr_human: env.contract.address.clone(),
+    })
+}
+
+fn query_pause_status(
+    deps: Deps,
+    env: &Env,
+    asset: Option<String>,
+) -> StdResult<PauseStatusResponse> {
+    let status = |since_block: u64| PauseStatus {
+        since_block,
+        paused: env.block.height >= since_block,
+    };
+    let public_api = status(api_paused_since_block(
+        deps.storage,
+        env,
+        &PausableOperation::PUBLIC_API,
+    )?);
+    let relayer_api = status(api_paused_since_block(
+        deps.storage,
+        env,
+        &PausableOperation::RELAYER_API,
+    )?);
+    let asset = asset
+        .map(|denom| -> StdResult<_> {
+            let asset = ASSETS.load(deps.storage, &denom)?;
+            Ok(AssetPauseStatus {
+                denom,
+                public_api: status(asset.paused_since_block_public_api),
+                relayer_api: status(asset.paused_since_block_relayer_api),
+            })
+        })
+        .transpose()?;
+
+    let operations = PausableOperation::ALL
+        .into_iter()
+        .map(|operation| {
+            let pause = PAUSES.may_load(deps.storage, operation.name())?;
+            let asset_paused = asset.as_ref().is_some_and(|asset| match operation {
+                PausableOperation::Swap => asset.public_api.paused,
+                PausableOperation::ReverseSwap | PausableOperation::Refund => {
+                    asset.relayer_api.paused
+                }
+                _ => false,
+            });
+            let paused = asset_paused || pause.as_ref().is_some_and(|pause| pause.active(env));
+            Ok(OperationPauseStatus {
+                operation,
+                pause,
+                paused,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PauseStatusResponse {
+        public_api,
+        relayer_api,
+        operations,
+        monitor_pause_max: MONITOR_PAUSE_MAX.may_load(deps.storage)?,
+        asset,
+    })
+}
+
+fn query_list_assets(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListAssetsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let assets = ASSETS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, asset)| asset))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ListAssetsResponse { assets })
+}
+
+fn query_claimable(deps: Deps, address: Addr) -> StdResult<ClaimableResponse> {
+    let claimable = CLAIMABLE
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ClaimableResponse { claimable })
+}
+
+fn query_list_claimable(
+    deps: Deps,
+    start_after: Option<(Addr, String)>,
+    limit: Option<u32>,
+) -> StdResult<ListClaimableResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|(address, denom)| Bound::exclusive((address, denom.as_str())));
+    let claimable = CLAIMABLE
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|((address, denom), amount)| ClaimableBalance {
+                address,
+                denom,
+                amount,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ListClaimableResponse { claimable })
+}
+
+fn query_address_list(
+    deps: Deps,
+    list: AddressList,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<AddressListResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let addresses = ADDRESS_LISTS
+        .prefix(list.name())
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AddressListResponse {
+        allowlist_enabled: allowlist_enabled(deps.storage)?,
+        addresses,
+    })
+}
+
+fn query_held_payouts(
+    deps: Deps,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> StdResult<HeldPayoutsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|(operation, id)| Bound::exclusive((operation.as_str(), *id)));
+    let held = HELD_PAYOUTS
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, payout)| payout))
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(HeldPayoutsResponse { held })
+}
+
+fn query_sender_usage(
+    deps: Deps,
+    env: &Env,
+    denom: &str,
+    address: Addr,
+) -> StdResult<SenderUsageResponse> {
+    let limits = match SENDER_LIMITS.may_load(deps.storage, denom)? {
+        Some(limits) => limits,
+        None => {
+            return Ok(SenderUsageResponse {
+                limits: None,
+                volume: Uint128::zero(),
+                swaps: 0,
+                resets_at: None,
+            });
+        }
+    };
+    let usage = sender_usage(
+        deps.storage,
+        denom,
+        &limits,
+        &address,
+        limits.window.now(env),
+    )?;
+
+    Ok(SenderUsageResponse {
+        resets_at: Some(usage.window_start.saturating_add(limits.window.period())),
+        volume: usage.volume,
+        swaps: usage.swaps,
+        limits: Some(limits),
+    })
+}
+
+fn query_outflow_capacity(
+    deps: Deps,
+    env: &Env,
+    denom: &str,
+) -> StdResult<OutflowCapacityResponse> {
+    let limit = match OUTFLOW_LIMIT.may_load(deps.storage, denom)? {
+        Some(limit) => limit,
+        None => {
+            return Ok(OutflowCapacityResponse {
+                limit: None,
+                available: None,
+                full_at: None,
+            });
+        }
+    };
+    let usage = OUTFLOW_USAGE.may_load(deps.storage, denom)?;
+    let now = limit.now(env);
+    let available = limit.available(usage.as_ref(), now);
+
+    Ok(OutflowCapacityResponse {
+        full_at: Some(limit.full_at(available, now)),
+        available: Some(available),
+        limit: Some(limit),
+    })
+}
+
+fn query_pending_attestations(
+    deps: Deps,
+    kind: AttestationKind,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PendingAttestationsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let pending = ATTESTATIONS
+        .prefix(kind.as_str())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(id, attestations)| PendingAttestation { id, attestations }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PendingAttestationsResponse {
+        relayer_threshold: relayer_threshold(deps.storage)?,
+        pending,
+    })
+}
+
+fn query_pending_operations(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PendingOperationsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let operations = QUEUED_OPERATIONS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, operation)| operation))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PendingOperationsResponse {
+        timelock_delay: timelock_delay(deps.storage)?,
+        operations,
+    })
+}
+
+fn query_list_role_members(
+    deps: Deps,
+    role: String,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<ListRoleMembersResponse> {
+    let role = AccessRole::from_str(role.as_str())?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(Bound::exclusive);
+    let members = ROLE_MEMBERS
+        .prefix(role.value())
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ListRoleMembersResponse { members })
+}
+
+fn query_refund(deps: Deps, id: u64) -> StdResult<RefundResponse> {
+    Ok(match refunds_get(id, deps.storage)? {
+        None => RefundResponse {
+            refunded: false,
+            refund: None,
+        },
+        Some(refund) => RefundResponse {
+            refunded: true,
+            refund,
+        },
+    })
+}
+
+fn query_list_swaps(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    sender: Option<Addr>,
+) -> StdResult<ListSwapsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let swaps = match sender {
+        Some(sender) => SWAPS_BY_SENDER
+            .prefix(&sender)
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|id| SWAPS.load(deps.storage, id?))
+            .collect::<StdResult<Vec<_>>>()?,
+        None => SWAPS
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, swap)| swap))
+            .collect::<StdResult<Vec<_>>>()?,
+    };
+
+    Ok(ListSwapsResponse { swaps })
+}
+
+fn query_reverse_swap_id_seal(
+    deps: Deps,
+    state: &State,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ReverseSwapIdSealResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let gaps = REVERSE_SWAP_ID_GAPS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, gap)| gap))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ReverseSwapIdSealResponse {
+        sealed_reverse_swap_id: state.sealed_reverse_swap_id,
+        relay_eon: state.relay_eon,
+        ordering: reverse_swap_id_ordering(deps.storage)?,
+        gaps,
+    })
+}
+
 fn query_role(deps: Deps, role: String, address: Addr) -> StdResult<RoleResponse> {
     match ac_have_role(
         deps.storage,
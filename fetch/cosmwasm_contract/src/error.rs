@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError, Uint128};
 use thiserror::Error;
 
 /* *********************** *
@@ -10,6 +10,9 @@ NB(LR) **don't** change, used as keywords by external code
 // limits error messages
 pub const ERR_SWAP_LIMITS_INCONSISTENT: &str =
     "[FET_ERR_SWAP_LIMITS_INCONSISTENT] inconsistent swap fee and swap limits";
+pub const ERR_INVALID_FEE_POLICY: &str = "[FET_ERR_INVALID_FEE_POLICY] Invalid fee policy";
+pub const ERR_DUST_REJECTED: &str = "[FET_ERR_DUST_REJECTED] Amount does not exceed fee";
+pub const ERR_NOTHING_TO_CLAIM: &str = "[FET_ERR_NOTHING_TO_CLAIM] Nothing to claim";
 pub const ERR_SWAP_LIMITS_VIOLATED: &str = "[FET_ERROR_SWAP_LIMITS] Swap limits violated";
 pub const ERR_SUPPLY_EXCEEDED: &str = "[FET_ERR_SUPPLY_EXCEEDED] Amount would exceed supply";
 pub const ERR_RA_ALLOWANCE_EXCEEDED: &str =
@@ -21,20 +24,346 @@ pub const ERR_ACCESS_CONTROL_ONLY_ADMIN: &str = "[FET_ERR_ACCESS_CONTROL] Only A
 pub const ERR_ACCESS_CONTROL_ONLY_RELAYER: &str = "[FET_ERR_ACCESS_CONTROL] Only Relayer";
 pub const ERR_ACCESS_CONTROL_ALREADY_HAS_ROLE: &str = "[FET_ERR_ACCESS_CONTROL] Already has role";
 pub const ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE: &str = "[FET_ERR_ACCESS_CONTROL] Doesn't have role";
+pub const ERR_ACCESS_CONTROL_LAST_ADMIN: &str = "[FET_ERR_ACCESS_CONTROL] Cannot remove last admin";
+pub const ERR_ACCESS_CONTROL_NOT_PENDING_ADMIN: &str = "[FET_ERR_ACCESS_CONTROL] Not pending admin";
+pub const ERR_ACCESS_CONTROL_ADMIN_PROPOSAL_EXPIRED: &str =
+    "[FET_ERR_ACCESS_CONTROL] Admin proposal expired";
+// swap
+pub const ERR_INVALID_DESTINATION: &str =
+    "[FET_ERR_INVALID_DESTINATION] Invalid destination address";
 // funds
 pub const ERR_UNRECOGNIZED_DENOM: &str = "[FET_ERR_UNRECOGNIZED_DENOM] unrecognized denom";
+// assets
+pub const ERR_UNKNOWN_ASSET: &str = "[FET_ERR_ASSET] Unknown asset";
+pub const ERR_ASSET_ALREADY_EXISTS: &str = "[FET_ERR_ASSET] Asset already exists";
+pub const ERR_ASSET_MISMATCH: &str = "[FET_ERR_ASSET] Asset differs from the swap asset";
+pub const ERR_ASSET_KIND: &str = "[FET_ERR_ASSET] Not supported for this kind of asset";
+// custody mode
+pub const ERR_CUSTODY_MODE_UNSUPPORTED: &str =
+    "[FET_ERR_CUSTODY_MODE] Not supported in the current custody mode";
+pub const ERR_CUSTODY_MODE_SUPPLY_OUTSTANDING: &str =
+    "[FET_ERR_CUSTODY_MODE] Custody mode can't change with supply outstanding";
+// tokenfactory denom
+pub const ERR_DENOM_SUPPLY_OUTSTANDING: &str =
+    "[FET_ERR_DENOM] Bridge denom can't change with supply or fees outstanding";
+pub const ERR_DENOM_BALANCES_OUTSTANDING: &str =
+    "[FET_ERR_DENOM] Bridge denom can't change with claimable or held payouts outstanding";
+pub const ERR_DENOM_ADMIN_SUPPLY_OUTSTANDING: &str =
+    "[FET_ERR_DENOM] Denom admin can't change with minted supply outstanding";
 // api paused
 pub const ERR_CONTRACT_PAUSED: &str = "[FET_ERR_CONTRACT_PAUSED] Contract is paused";
+pub const ERR_ADDRESS_BLOCKED: &str = "[FET_ERR_ADDRESS_BLOCKED] Address is blocked";
+pub const ERR_HELD_PAYOUT_NOT_FOUND: &str = "[FET_ERR_HELD_PAYOUT] No such held payout";
+pub const ERR_PAUSE_NOT_EXPIRING: &str =
+    "[FET_ERR_PAUSE] No expiring pause of the operation to confirm";
 // eon
 pub const ERR_EON: &str = "[FET_ERR_EON] Tx doesn't belong to current relayEon";
 // refund
 pub const ERR_INVALID_SWAP_ID: &str = "[FET_ERR_INVALID_SWAP_ID] Invalid swap id";
 pub const ERR_ALREADY_REFUNDED: &str = "[FET_ERR_ALREADY_REFUNDED] Refund was already processed";
+pub const ERR_REFUND_AMOUNT_EXCEEDED: &str =
+    "[FET_ERR_INVALID_REFUND_AMOUNT] Refund amount exceeds original swap amount";
+pub const ERR_REFUND_AMOUNT_NOT_FULL: &str =
+    "[FET_ERR_INVALID_REFUND_AMOUNT] Refund in full amount differs from original swap amount";
+// reverse swap
+pub const ERR_ALREADY_REVERSE_SWAPPED: &str =
+    "[FET_ERR_ALREADY_REVERSE_SWAPPED] Reverse swap was already processed";
+pub const ERR_REVERSE_SWAP_ID_OUT_OF_ORDER: &str =
+    "[FET_ERR_REVERSE_SWAP_ID_ORDER] Reverse swap id is below the sealed id";
+pub const ERR_REVERSE_SWAP_ID_GAP_EXCEEDED: &str =
+    "[FET_ERR_REVERSE_SWAP_ID_ORDER] Reverse swap id gap exceeds tolerance";
+// per sender limits
+pub const ERR_SENDER_VOLUME_EXCEEDED: &str =
+    "[FET_ERR_SENDER_LIMIT] Amount would exceed sender swap volume";
+pub const ERR_SENDER_SWAP_COUNT_EXCEEDED: &str =
+    "[FET_ERR_SENDER_LIMIT] Sender swap count exceeded";
+pub const ERR_SENDER_LIMITS_INVALID: &str = "[FET_ERR_SENDER_LIMIT] Sender window must not be 0";
+// outflow rate limit
+pub const ERR_OUTFLOW_LIMIT_EXCEEDED: &str =
+    "[FET_ERR_OUTFLOW_LIMIT] Amount would exceed outflow rate limit";
+pub const ERR_OUTFLOW_LIMIT_INVALID: &str = "[FET_ERR_OUTFLOW_LIMIT] Outflow window must not be 0";
+// relayer attestations
+pub const ERR_ALREADY_ATTESTED: &str =
+    "[FET_ERR_ALREADY_ATTESTED] Relayer already attested this operation";
+pub const ERR_INVALID_RELAYER_THRESHOLD: &str =
+    "[FET_ERR_INVALID_RELAYER_THRESHOLD] Relayer threshold must be at least 1";
+// timelock
+pub const ERR_TIMELOCK_UNKNOWN_OPERATION: &str = "[FET_ERR_TIMELOCK] Unknown queued operation";
+pub const ERR_TIMELOCK_NOT_READY: &str = "[FET_ERR_TIMELOCK] Queued operation is still locked";
+pub const ERR_TIMELOCK_STALE_UNPAUSE: &str =
+    "[FET_ERR_TIMELOCK] Queued unpause start already reached";
 #[derive(Error, Debug)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
-    #[error("FET_ERR_CODE_1")]
-    Unauthorized {},
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    // limits
+    #[error("{}", ERR_SWAP_LIMITS_INCONSISTENT)]
+    SwapLimitsInconsistent {},
+
+    #[error("{}", ERR_INVALID_FEE_POLICY)]
+    InvalidFeePolicy {},
+
+    #[error("{} (amount {amount}, fee {fee})", ERR_DUST_REJECTED)]
+    DustRejected { amount: Uint128, fee: Uint128 },
+
+    #[error("{}", ERR_NOTHING_TO_CLAIM)]
+    NothingToClaim {},
+
+    #[error("{} (amount {amount}, min {min}, max {max})", ERR_SWAP_LIMITS_VIOLATED)]
+    SwapLimitsViolated {
+        amount: Uint128,
+        min: Uint128,
+        max: Uint128,
+    },
+
+    #[error(
+        "{} (requested {requested}, available {available})",
+        ERR_SUPPLY_EXCEEDED
+    )]
+    SupplyExceeded {
+        requested: Uint128,
+        available: Uint128,
+    },
+
+    #[error(
+        "{} (requested {requested}, available {available})",
+        ERR_RA_ALLOWANCE_EXCEEDED
+    )]
+    RaAllowanceExceeded {
+        requested: Uint128,
+        available: Uint128,
+    },
+
+    #[error("{} (resulting supply {supply}, cap {cap})", ERR_CAP_EXCEEDED)]
+    CapExceeded { supply: Uint128, cap: Uint128 },
+
+    // access control
+    #[error("{}", ERR_ACCESS_CONTROL_ONLY_ADMIN)]
+    OnlyAdmin {},
+
+    #[error("{}", ERR_ACCESS_CONTROL_ONLY_RELAYER)]
+    OnlyRelayer {},
+
+    #[error("{}", ERR_ACCESS_CONTROL_ALREADY_HAS_ROLE)]
+    AlreadyHasRole {},
+
+    #[error("{}", ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE)]
+    DoesntHaveRole {},
+
+    #[error("{}", ERR_ACCESS_CONTROL_LAST_ADMIN)]
+    LastAdmin {},
+
+    #[error("{}", ERR_ACCESS_CONTROL_NOT_PENDING_ADMIN)]
+    NotPendingAdmin {},
+
+    #[error(
+        "{} (expired at block {expires_at_block})",
+        ERR_ACCESS_CONTROL_ADMIN_PROPOSAL_EXPIRED
+    )]
+    AdminProposalExpired { expires_at_block: u64 },
+
+    // swap
+    #[error("{} ({destination})", ERR_INVALID_DESTINATION)]
+    InvalidDestination { destination: String },
+
+    // funds
+    #[error("{} (expected {denom})", ERR_UNRECOGNIZED_DENOM)]
+    UnrecognizedDenom { denom: String },
+
+    // assets
+    #[error("{} ({denom})", ERR_UNKNOWN_ASSET)]
+    UnknownAsset { denom: String },
+
+    #[error("{} ({denom})", ERR_ASSET_ALREADY_EXISTS)]
+    AssetAlreadyExists { denom: String },
+
+    #[error("{} (expected {expected}, got {denom})", ERR_ASSET_MISMATCH)]
+    AssetMismatch { expected: String, denom: String },
+
+    #[error("{} ({denom})", ERR_ASSET_KIND)]
+    AssetKindMismatch { denom: String },
+
+    // custody mode
+    #[error("{}", ERR_CUSTODY_MODE_UNSUPPORTED)]
+    CustodyModeUnsupported {},
+
+    #[error("{} ({supply} {denom})", ERR_CUSTODY_MODE_SUPPLY_OUTSTANDING)]
+    CustodyModeSupplyOutstanding { denom: String, supply: Uint128 },
+
+    // tokenfactory denom
+    #[error("{} ({denom})", ERR_DENOM_SUPPLY_OUTSTANDING)]
+    DenomSupplyOutstanding { denom: String },
+
+    #[error("{} ({denom})", ERR_DENOM_BALANCES_OUTSTANDING)]
+    DenomBalancesOutstanding { denom: String },
+
+    #[error("{} ({supply} {denom})", ERR_DENOM_ADMIN_SUPPLY_OUTSTANDING)]
+    DenomAdminSupplyOutstanding { denom: String, supply: Uint128 },
+
+    // api paused
+    #[error("{} (since block {since_block})", ERR_CONTRACT_PAUSED)]
+    ContractPaused { since_block: u64 },
+
+    #[error("{} (since {since}: {reason})", ERR_CONTRACT_PAUSED)]
+    OperationPaused { since: String, reason: String },
+
+    #[error("{}", ERR_PAUSE_NOT_EXPIRING)]
+    PauseNotExpiring {},
+
+    #[error("{} ({address})", ERR_ADDRESS_BLOCKED)]
+    AddressBlocked { address: String },
+
+    #[error("{} ({operation} {id})", ERR_HELD_PAYOUT_NOT_FOUND)]
+    HeldPayoutNotFound { operation: String, id: u64 },
+
+    // eon
+    #[error("{} (tx eon {tx_eon}, current eon {current_eon})", ERR_EON)]
+    Eon { tx_eon: u64, current_eon: u64 },
+
+    // refund
+    #[error("{} ({id})", ERR_INVALID_SWAP_ID)]
+    InvalidSwapId { id: u64 },
+
+    #[error("{} ({id})", ERR_ALREADY_REFUNDED)]
+    AlreadyRefunded { id: u64 },
+
+    #[error("{} (refund {amount}, swap {swap_amount})", ERR_REFUND_AMOUNT_EXCEEDED)]
+    RefundAmountExceeded {
+        amount: Uint128,
+        swap_amount: Uint128,
+    },
+
+    #[error("{} (refund {amount}, swap {swap_amount})", ERR_REFUND_AMOUNT_NOT_FULL)]
+    RefundAmountNotFull {
+        amount: Uint128,
+        swap_amount: Uint128,
+    },
+
+    // reverse swap
+    #[error("{} ({rid})", ERR_ALREADY_REVERSE_SWAPPED)]
+    AlreadyReverseSwapped { rid: u64 },
+
+    #[error("{} (rid {rid}, sealed {sealed})", ERR_REVERSE_SWAP_ID_OUT_OF_ORDER)]
+    ReverseSwapIdOutOfOrder { rid: u64, sealed: u64 },
+
+    #[error(
+        "{} (rid {rid}, sealed {sealed}, max gap {max_gap})",
+        ERR_REVERSE_SWAP_ID_GAP_EXCEEDED
+    )]
+    ReverseSwapIdGapExceeded { rid: u64, sealed: u64, max_gap: u64 },
+
+    // per sender limits
+    #[error(
+        "{} (requested {requested}, available {available})",
+        ERR_SENDER_VOLUME_EXCEEDED
+    )]
+    SenderVolumeExceeded {
+        requested: Uint128,
+        available: Uint128,
+    },
+
+    #[error("{} (max {max_swaps})", ERR_SENDER_SWAP_COUNT_EXCEEDED)]
+    SenderSwapCountExceeded { max_swaps: u32 },
+
+    #[error("{}", ERR_SENDER_LIMITS_INVALID)]
+    SenderLimitsInvalid {},
+
+    // outflow rate limit
+    #[error(
+        "{} (requested {requested}, available {available})",
+        ERR_OUTFLOW_LIMIT_EXCEEDED
+    )]
+    OutflowLimitExceeded {
+        requested: Uint128,
+        available: Uint128,
+    },
+
+    #[error("{}", ERR_OUTFLOW_LIMIT_INVALID)]
+    OutflowLimitInvalid {},
+
+    // relayer attestations
+    #[error("{} ({id})", ERR_ALREADY_ATTESTED)]
+    AlreadyAttested { id: u64 },
+
+    #[error("{}", ERR_INVALID_RELAYER_THRESHOLD)]
+    InvalidRelayerThreshold {},
+
+    // timelock
+    #[error("{} ({id})", ERR_TIMELOCK_UNKNOWN_OPERATION)]
+    TimelockUnknownOperation { id: u64 },
+
+    #[error(
+        "{} (id {id}, executable at block {execute_at_block})",
+        ERR_TIMELOCK_NOT_READY
+    )]
+    TimelockNotReady { id: u64, execute_at_block: u64 },
+
+    #[error("{} (since {since})", ERR_TIMELOCK_STALE_UNPAUSE)]
+    TimelockStaleUnpause { since: String },
+}
+
+impl ContractError {
+    /// Stable `FET_ERR_*` code of the error, `None` for errors not raised by the contract logic
+    pub fn code(&self) -> Option<&'static str> {
+        let msg = match self {
+            ContractError::Std(_) | ContractError::Overflow(_) => return None,
+            ContractError::SwapLimitsInconsistent {} => ERR_SWAP_LIMITS_INCONSISTENT,
+            ContractError::InvalidFeePolicy {} => ERR_INVALID_FEE_POLICY,
+            ContractError::DustRejected { .. } => ERR_DUST_REJECTED,
+            ContractError::NothingToClaim {} => ERR_NOTHING_TO_CLAIM,
+            ContractError::SwapLimitsViolated { .. } => ERR_SWAP_LIMITS_VIOLATED,
+            ContractError::SupplyExceeded { .. } => ERR_SUPPLY_EXCEEDED,
+            ContractError::RaAllowanceExceeded { .. } => ERR_RA_ALLOWANCE_EXCEEDED,
+            ContractError::CapExceeded { .. } => ERR_CAP_EXCEEDED,
+            ContractError::OnlyAdmin {} => ERR_ACCESS_CONTROL_ONLY_ADMIN,
+            ContractError::OnlyRelayer {} => ERR_ACCESS_CONTROL_ONLY_RELAYER,
+            ContractError::AlreadyHasRole {} => ERR_ACCESS_CONTROL_ALREADY_HAS_ROLE,
+            ContractError::DoesntHaveRole {} => ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE,
+            ContractError::LastAdmin {} => ERR_ACCESS_CONTROL_LAST_ADMIN,
+            ContractError::NotPendingAdmin {} => ERR_ACCESS_CONTROL_NOT_PENDING_ADMIN,
+            ContractError::AdminProposalExpired { .. } => ERR_ACCESS_CONTROL_ADMIN_PROPOSAL_EXPIRED,
+            ContractError::InvalidDestination { .. } => ERR_INVALID_DESTINATION,
+            ContractError::UnrecognizedDenom { .. } => ERR_UNRECOGNIZED_DENOM,
+            ContractError::UnknownAsset { .. } => ERR_UNKNOWN_ASSET,
+            ContractError::AssetAlreadyExists { .. } => ERR_ASSET_ALREADY_EXISTS,
+            ContractError::AssetMismatch { .. } => ERR_ASSET_MISMATCH,
+            ContractError::AssetKindMismatch { .. } => ERR_ASSET_KIND,
+            ContractError::CustodyModeUnsupported {} => ERR_CUSTODY_MODE_UNSUPPORTED,
+            ContractError::CustodyModeSupplyOutstanding { .. } => {
+                ERR_CUSTODY_MODE_SUPPLY_OUTSTANDING
+            }
+            ContractError::DenomSupplyOutstanding { .. } => ERR_DENOM_SUPPLY_OUTSTANDING,
+            ContractError::DenomBalancesOutstanding { .. } => ERR_DENOM_BALANCES_OUTSTANDING,
+            ContractError::DenomAdminSupplyOutstanding { .. } => ERR_DENOM_ADMIN_SUPPLY_OUTSTANDING,
+            ContractError::ContractPaused { .. } => ERR_CONTRACT_PAUSED,
+            ContractError::OperationPaused { .. } => ERR_CONTRACT_PAUSED,
+            ContractError::PauseNotExpiring {} => ERR_PAUSE_NOT_EXPIRING,
+            ContractError::AddressBlocked { .. } => ERR_ADDRESS_BLOCKED,
+            ContractError::HeldPayoutNotFound { .. } => ERR_HELD_PAYOUT_NOT_FOUND,
+            ContractError::Eon { .. } => ERR_EON,
+            ContractError::InvalidSwapId { .. } => ERR_INVALID_SWAP_ID,
+            ContractError::AlreadyRefunded { .. } => ERR_ALREADY_REFUNDED,
+            ContractError::RefundAmountExceeded { .. } => ERR_REFUND_AMOUNT_EXCEEDED,
+            ContractError::RefundAmountNotFull { .. } => ERR_REFUND_AMOUNT_NOT_FULL,
+            ContractError::AlreadyReverseSwapped { .. } => ERR_ALREADY_REVERSE_SWAPPED,
+            ContractError::ReverseSwapIdOutOfOrder { .. } => ERR_REVERSE_SWAP_ID_OUT_OF_ORDER,
+            ContractError::ReverseSwapIdGapExceeded { .. } => ERR_REVERSE_SWAP_ID_GAP_EXCEEDED,
+            ContractError::SenderVolumeExceeded { .. } => ERR_SENDER_VOLUME_EXCEEDED,
+            ContractError::SenderSwapCountExceeded { .. } => ERR_SENDER_SWAP_COUNT_EXCEEDED,
+            ContractError::SenderLimitsInvalid {} => ERR_SENDER_LIMITS_INVALID,
+            ContractError::OutflowLimitExceeded { .. } => ERR_OUTFLOW_LIMIT_EXCEEDED,
+            ContractError::OutflowLimitInvalid {} => ERR_OUTFLOW_LIMIT_INVALID,
+            ContractError::AlreadyAttested { .. } => ERR_ALREADY_ATTESTED,
+            ContractError::InvalidRelayerThreshold {} => ERR_INVALID_RELAYER_THRESHOLD,
+            ContractError::TimelockUnknownOperation { .. } => ERR_TIMELOCK_UNKNOWN_OPERATION,
+            ContractError::TimelockNotReady { .. } => ERR_TIMELOCK_NOT_READY,
+            ContractError::TimelockStaleUnpause { .. } => ERR_TIMELOCK_STALE_UNPAUSE,
+        };
+        // every message starts with "[<code>]"
+        msg.strip_prefix('[').and_then(|m| m.split(']').next())
+    }
 }
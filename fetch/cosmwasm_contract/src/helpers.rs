@@ -1,7 +1,10 @@
 use cosmwasm_std::{AnyMsg, CosmosMsg, Env, StdError};
 use prost::Message;
+use sha3::{Digest, Keccak256};
 
+use crate::error::ContractError;
 use crate::msg::Uint128;
+use crate::state::TokenfactoryDialect;
 
 #[derive(Clone, PartialEq, Message)]
 pub struct Coin {
@@ -11,6 +14,8 @@ pub struct Coin {
     pub amount: String,
 }
 
+// the field numbers are the same in every dialect, Injective names `mint_to_address` its
+// `receiver`
 #[derive(Clone, PartialEq, Message)]
 pub struct MsgMint {
     #[prost(string, tag = "1")]
@@ -35,10 +40,119 @@ pub struct MsgBurn {
     pub burn_from_address: String,
 }
 
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+
+    #[prost(string, tag = "2")]
+    pub subdenom: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgChangeAdmin {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+
+    #[prost(string, tag = "2")]
+    pub denom: String,
+
+    #[prost(string, tag = "3")]
+    pub new_admin: String,
+}
+
+// cosmos.bank.v1beta1.DenomUnit
+#[derive(Clone, PartialEq, Message)]
+pub struct DenomUnit {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+
+    #[prost(uint32, tag = "2")]
+    pub exponent: u32,
+
+    #[prost(string, repeated, tag = "3")]
+    pub aliases: Vec<String>,
+}
+
+// cosmos.bank.v1beta1.Metadata
+#[derive(Clone, PartialEq, Message)]
+pub struct Metadata {
+    #[prost(string, tag = "1")]
+    pub description: String,
+
+    #[prost(message, repeated, tag = "2")]
+    pub denom_units: Vec<DenomUnit>,
+
+    #[prost(string, tag = "3")]
+    pub base: String,
+
+    #[prost(string, tag = "4")]
+    pub display: String,
+
+    #[prost(string, tag = "5")]
+    pub name: String,
+
+    #[prost(string, tag = "6")]
+    pub symbol: String,
+
+    #[prost(string, tag = "7")]
+    pub uri: String,
+
+    #[prost(string, tag = "8")]
+    pub uri_hash: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgSetDenomMetadata {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+
+    #[prost(message, tag = "2")]
+    pub metadata: Option<Metadata>,
+}
+
+/// Type url of the tokenfactory message `name` (e.g. `MsgMint`) in the given dialect
+pub fn tokenfactory_type_url(dialect: TokenfactoryDialect, name: &str) -> String {
+    let package = match dialect {
+        TokenfactoryDialect::Osmosis => "osmosis.tokenfactory.v1beta1",
+        TokenfactoryDialect::Injective => "injective.tokenfactory.v1beta1",
+    };
+    format!("/{package}.{name}")
+}
+
+fn tokenfactory_msg<M: Message>(
+    dialect: TokenfactoryDialect,
+    name: &str,
+    msg: &M,
+) -> Result<CosmosMsg, StdError> {
+    let mut buf = Vec::new();
+    msg.encode(&mut buf)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    let cosmos_msg = CosmosMsg::Any(AnyMsg {
+        type_url: tokenfactory_type_url(dialect, name),
+        value: buf.into(),
+    });
+
+    Ok(cosmos_msg)
+}
+
 pub fn mint_tokens_to_contract(
     env: &Env,
+    dialect: TokenfactoryDialect,
     denom: String,
     amount: Uint128,
+) -> Result<CosmosMsg, StdError> {
+    mint_tokens_to_address(env, dialect, denom, amount, "".to_string())
+}
+
+// an empty `mint_to_address` mints to the contract
+pub fn mint_tokens_to_address(
+    env: &Env,
+    dialect: TokenfactoryDialect,
+    denom: String,
+    amount: Uint128,
+    mint_to_address: String,
 ) -> Result<CosmosMsg, StdError> {
     let msg = MsgMint {
         sender: env.contract.address.to_string(),
@@ -46,23 +160,79 @@ pub fn mint_tokens_to_contract(
             denom,
             amount: amount.to_string(),
         }),
-        mint_to_address: "".to_string(),
+        mint_to_address,
     };
 
-    let mut buf = Vec::new();
-    msg.encode(&mut buf)
-        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    tokenfactory_msg(dialect, "MsgMint", &msg)
+}
 
-    let cosmos_msg = CosmosMsg::Any(AnyMsg {
-        type_url: "/osmosis.tokenfactory.v1beta1.MsgMint".to_string(),
-        value: buf.into(),
-    });
+/// Denom created by the contract for `subdenom`
+pub fn tokenfactory_denom(env: &Env, subdenom: &str) -> String {
+    format!("factory/{}/{}", env.contract.address, subdenom)
+}
 
-    Ok(cosmos_msg)
+pub fn create_denom(
+    env: &Env,
+    dialect: TokenfactoryDialect,
+    subdenom: String,
+) -> Result<CosmosMsg, StdError> {
+    let msg = MsgCreateDenom {
+        sender: env.contract.address.to_string(),
+        subdenom,
+    };
+
+    tokenfactory_msg(dialect, "MsgCreateDenom", &msg)
+}
+
+pub fn change_denom_admin(
+    env: &Env,
+    dialect: TokenfactoryDialect,
+    denom: String,
+    new_admin: String,
+) -> Result<CosmosMsg, StdError> {
+    let msg = MsgChangeAdmin {
+        sender: env.contract.address.to_string(),
+        denom,
+        new_admin,
+    };
+
+    tokenfactory_msg(dialect, "MsgChangeAdmin", &msg)
+}
+
+pub fn set_denom_metadata(
+    env: &Env,
+    dialect: TokenfactoryDialect,
+    metadata: cosmwasm_std::DenomMetadata,
+) -> Result<CosmosMsg, StdError> {
+    let msg = MsgSetDenomMetadata {
+        sender: env.contract.address.to_string(),
+        metadata: Some(Metadata {
+            description: metadata.description,
+            denom_units: metadata
+                .denom_units
+                .into_iter()
+                .map(|unit| DenomUnit {
+                    denom: unit.denom,
+                    exponent: unit.exponent,
+                    aliases: unit.aliases,
+                })
+                .collect(),
+            base: metadata.base,
+            display: metadata.display,
+            name: metadata.name,
+            symbol: metadata.symbol,
+            uri: metadata.uri,
+            uri_hash: metadata.uri_hash,
+        }),
+    };
+
+    tokenfactory_msg(dialect, "MsgSetDenomMetadata", &msg)
 }
 
+// all dialects share the layout of `MsgBurn`
 pub fn burn_tokens_from_contract(
     env: &Env,
+    dialect: TokenfactoryDialect,
     denom: String,
     amount: Uint128,
 ) -> Result<CosmosMsg, StdError> {
@@ -75,14 +245,40 @@ pub fn burn_tokens_from_contract(
         burn_from_address: "".to_string(),
     };
 
-    let mut buf = Vec::new();
-    msg.encode(&mut buf)
-        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    tokenfactory_msg(dialect, "MsgBurn", &msg)
+}
 
-    let cosmos_msg = CosmosMsg::Any(AnyMsg {
-        type_url: "/osmosis.tokenfactory.v1beta1.MsgBurn".to_string(),
-        value: buf.into(),
-    });
+/// Validates an Ethereum address (0x prefixed, 20 bytes hex encoded) and
+/// returns its EIP-55 checksummed form. Mixed case input must carry a valid checksum.
+pub fn normalize_eth_address(address: &str) -> Result<String, ContractError> {
+    let invalid_destination = || ContractError::InvalidDestination {
+        destination: address.to_string(),
+    };
+    let hex_part = address
+        .strip_prefix("0x")
+        .filter(|h| h.len() == 40 && h.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(invalid_destination)?;
 
-    Ok(cosmos_msg)
+    let lowercase = hex_part.to_ascii_lowercase();
+    let hash = Keccak256::digest(lowercase.as_bytes());
+    let checksummed: String = lowercase
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if c.is_ascii_alphabetic() && nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    let is_mixed_case = hex_part.chars().any(|c| c.is_ascii_lowercase())
+        && hex_part.chars().any(|c| c.is_ascii_uppercase());
+    if is_mixed_case && hex_part != checksummed {
+        return Err(invalid_destination());
+    }
+
+    Ok(format!("0x{}", checksummed))
 }
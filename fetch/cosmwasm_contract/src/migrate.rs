@@ -1,41 +1,252 @@
-use crate::msg::MigrateMsg;
-use crate::state::CONFIG;
-use cosmwasm_std::{DepsMut, Env, Response, StdResult, entry_point};
+use crate::access_control::ac_rebuild_role_index;
+use crate::contract::{rekey_default_asset, save_api_pause};
+use crate::error::ContractError;
+use crate::msg::{InstantiateMsg, MigrateMsg};
+use crate::state::{ASSETS, CONFIG, FeePolicy, PausableOperation, TOKENFACTORY_DIALECT};
+use cosmwasm_std::{DepsMut, Env, Response, Storage, attr, entry_point};
+use semver::Version;
 
 // version info for migration info
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// State migration applied when upgrading from a version older than `version`
+pub struct Migration {
+    pub version: &'static str,
+    pub migrate: fn(&mut dyn Storage, &Env) -> Result<(), ContractError>,
+}
+
+// NB(LR) keep ordered by version, migrations must be idempotent as a forced
+// migration without stored version info runs all of them
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: "0.4.0",
+        migrate: v0_3::migrate_state,
+    },
+    Migration {
+        version: "0.4.0",
+        migrate: index_role_members,
+    },
+];
+
 #[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let forced = msg.forced == Some(true);
+    let current_version = parse_version(CONTRACT_VERSION)?;
+
     // Skip checks if forced migration
-    if msg.forced != Some(true) {
+    if !forced {
         let ver = cw2::get_contract_version(deps.storage)?;
         // ensure we are migrating from an allowed contract
         if ver.contract != CONTRACT_NAME {
-            return Err(error::different_contract_type_error());
+            return Err(error::different_contract_type_error().into());
+        }
+        if parse_version(&ver.version)? >= current_version {
+            return Err(error::wrong_contract_version_error(&ver.version, CONTRACT_VERSION).into());
         }
-        // note: better to do proper semver compare, but string compare *usually* works
-        #[allow(clippy::cmp_owned)]
-        if ver.version >= CONTRACT_VERSION.to_string() {
-            return Err(error::wrong_contract_version_error(
-                &ver.version,
-                CONTRACT_VERSION,
-            ));
+    }
+
+    if msg.instantiate_msg.is_some() && !forced {
+        return Err(error::instantiate_msg_not_forced_error().into());
+    }
+
+    // unknown stored version, only possible with forced migration: run every migration
+    let from_version = cw2::get_contract_version(deps.storage)
+        .ok()
+        .and_then(|ver| Version::parse(&ver.version).ok());
+
+    let mut attrs = vec![
+        attr("action", "migrate"),
+        attr(
+            "from_version",
+            from_version
+                .as_ref()
+                .map_or("unknown".to_string(), |v| v.to_string()),
+        ),
+        attr("to_version", CONTRACT_VERSION),
+    ];
+
+    for migration in MIGRATIONS {
+        let version = parse_version(migration.version)?;
+        let pending = from_version.as_ref().is_none_or(|from| *from < version);
+        if pending && version <= current_version {
+            (migration.migrate)(deps.storage, &env)?;
+            attrs.push(attr("state_migration", migration.version));
         }
     }
 
     // Ensure correct contract state
     if CONFIG.load(deps.storage).is_err() {
-        return Err(error::state_storage_error());
+        return Err(error::state_storage_error().into());
+    }
+
+    if let Some(instantiate_msg) = msg.instantiate_msg {
+        reset_config(deps.storage, &env, instantiate_msg)?;
+        attrs.push(attr("config_reset", "true"));
+    }
+
+    if let Some(dialect) = msg.tokenfactory_dialect {
+        TOKENFACTORY_DIALECT.save(deps.storage, &dialect)?;
     }
 
     // set the new version
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    // do any desired state migrations...
+    Ok(Response::new().add_attributes(attrs))
+}
 
-    Ok(Response::new())
+fn index_role_members(storage: &mut dyn Storage, _env: &Env) -> Result<(), ContractError> {
+    ac_rebuild_role_index(storage)?;
+    Ok(())
+}
+
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    Version::parse(version).map_err(|_| error::invalid_version_error(version).into())
+}
+
+// Overwrites the configuration of the contract (pauses) and of its default asset (limits,
+// fees, cap, allowances, denom) with the values of `msg`, accounting (supply, fees, ids,
+// eon) is kept. `next_swap_id` can only be moved forward so swap ids are never reused.
+// The other assets are left untouched.
+fn reset_config(
+    storage: &mut dyn Storage,
+    env: &Env,
+    msg: InstantiateMsg,
+) -> Result<(), ContractError> {
+    FeePolicy::Flat { fee: msg.swap_fee }
+        .verify_limits(msg.lower_swap_limit, msg.upper_swap_limit)?;
+
+    let paused_since_block = msg
+        .paused_since_block
+        .unwrap_or(u64::MAX)
+        .max(env.block.height);
+
+    let mut state = CONFIG.load(storage)?;
+    let mut asset = ASSETS.load(storage, &state.denom)?;
+    asset.cap = msg.cap;
+    asset.upper_swap_limit = msg.upper_swap_limit;
+    asset.lower_swap_limit = msg.lower_swap_limit;
+    asset.swap_fee = msg.swap_fee;
+    asset.fee_policy = None;
+    asset.reverse_aggregated_allowance = msg.reverse_aggregated_allowance;
+    asset.reverse_aggregated_allowance_approver_cap = msg.reverse_aggregated_allowance_approver_cap;
+
+    // a new denom re-keys the default asset, its accounting moves along
+    if let Some(denom) = msg.denom
+        && denom != state.denom
+    {
+        rekey_default_asset(storage, &mut state, &mut asset, denom)?;
+    }
+
+    if let Some(dialect) = msg.tokenfactory_dialect {
+        TOKENFACTORY_DIALECT.save(storage, &dialect)?;
+    }
+
+    state.next_swap_id = state.next_swap_id.max(msg.next_swap_id);
+    for operations in [
+        &PausableOperation::PUBLIC_API[..],
+        &PausableOperation::RELAYER_API,
+    ] {
+        save_api_pause(
+            storage,
+            operations,
+            paused_since_block,
+            &env.contract.address,
+        )?;
+    }
+
+    ASSETS.save(storage, &asset.denom, &asset)?;
+    CONFIG.save(storage, &state)?;
+    Ok(())
+}
+
+/// Layout of the state up to version 0.3 included
+pub mod v0_3 {
+    use cosmwasm_std::{Addr, Env, Storage, from_json};
+    use cw_storage_plus::Item;
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    use crate::contract::save_api_pause;
+    use crate::error::ContractError;
+    use crate::msg::Uint128;
+    use crate::state::{ASSETS, Asset, AssetKind, CONFIG, CONFIG_KEY, PausableOperation, State};
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct StateV0_3 {
+        pub supply: Uint128,
+        pub fees_accrued: Uint128,
+        pub next_swap_id: u64,
+        pub sealed_reverse_swap_id: u64,
+        pub relay_eon: u64,
+        pub upper_swap_limit: Uint128,
+        pub lower_swap_limit: Uint128,
+        pub reverse_aggregated_allowance: Uint128,
+        pub reverse_aggregated_allowance_approver_cap: Uint128,
+        pub cap: Uint128,
+        pub swap_fee: Uint128,
+        pub paused_since_block_public_api: u64,
+        pub paused_since_block_relayer_api: u64,
+        pub denom: String,
+        pub contract_addr_human: Addr,
+    }
+
+    pub const CONFIG_V0_3: Item<StateV0_3> = Item::new(CONFIG_KEY);
+
+    // drops the obsolete `contract_addr_human`, moves the configuration and accounting of
+    // the token to its asset, and the pauses of the APIs to the pauses of their operations
+    pub fn migrate_state(storage: &mut dyn Storage, env: &Env) -> Result<(), ContractError> {
+        let Some(raw) = storage.get(CONFIG_KEY.as_bytes()) else {
+            return Ok(());
+        };
+        let old: StateV0_3 = match from_json(&raw) {
+            Ok(old) => old,
+            // already migrated
+            Err(_) if from_json::<State>(&raw).is_ok() => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let asset = Asset {
+            denom: old.denom.clone(),
+            kind: AssetKind::Native,
+            supply: old.supply,
+            fees_accrued: old.fees_accrued,
+            cap: old.cap,
+            upper_swap_limit: old.upper_swap_limit,
+            lower_swap_limit: old.lower_swap_limit,
+            swap_fee: old.swap_fee,
+            fee_policy: None,
+            reverse_aggregated_allowance: old.reverse_aggregated_allowance,
+            reverse_aggregated_allowance_approver_cap: old
+                .reverse_aggregated_allowance_approver_cap,
+            // the pauses of the contract keep covering the asset
+            paused_since_block_public_api: u64::MAX,
+            paused_since_block_relayer_api: u64::MAX,
+        };
+        let state = State {
+            next_swap_id: old.next_swap_id,
+            sealed_reverse_swap_id: old.sealed_reverse_swap_id,
+            relay_eon: old.relay_eon,
+            denom: old.denom,
+        };
+        ASSETS.save(storage, &asset.denom, &asset)?;
+        CONFIG.save(storage, &state)?;
+        let pauses = [
+            (
+                &PausableOperation::PUBLIC_API[..],
+                old.paused_since_block_public_api,
+            ),
+            (
+                &PausableOperation::RELAYER_API[..],
+                old.paused_since_block_relayer_api,
+            ),
+        ];
+        for (operations, since_block) in pauses {
+            save_api_pause(storage, operations, since_block, &env.contract.address)?;
+        }
+        Ok(())
+    }
 }
 
 pub mod error {
@@ -52,6 +263,14 @@ pub mod error {
         ))
     }
 
+    pub fn invalid_version_error(version: &str) -> StdError {
+        StdError::generic_err(format!("Invalid contract version {}.", version))
+    }
+
+    pub fn instantiate_msg_not_forced_error() -> StdError {
+        StdError::generic_err("Config can only be reset with a forced migration.")
+    }
+
     pub fn state_storage_error() -> StdError {
         StdError::generic_err("Contract STATE storage not correctly initialised")
     }
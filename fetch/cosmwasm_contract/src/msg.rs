@@ -2,7 +2,16 @@ use cosmwasm_schema::cw_serde;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin, DenomMetadata};
+use cw20::Cw20ReceiveMsg;
+
+use crate::access_control::PendingAdmin;
+use crate::state::{
+    AddressList, Asset, AssetKind, Attestation, AttestationKind, CustodyMode, DustPolicy,
+    FeePolicy, OperationPause, OutflowLimit, PausableOperation, PauseStart, Payout, PayoutMode,
+    QueuedOperation, RefundRecord, ReverseSwapIdGap, ReverseSwapIdOrdering, SenderLimits,
+    SwapRecord, TokenfactoryDialect,
+};
 
 //use crate::cosmwasm_bignumber::{Uint256};
 
@@ -12,6 +21,8 @@ pub type Uint128 = cosmwasm_std::Uint128;
 pub struct MigrateMsg {
     pub forced: Option<bool>, // Some(True) skips migration checks
     pub instantiate_msg: Option<InstantiateMsg>,
+    // none keeps the current dialect
+    pub tokenfactory_dialect: Option<TokenfactoryDialect>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -25,14 +36,42 @@ pub struct InstantiateMsg {
     pub reverse_aggregated_allowance_approver_cap: Uint128,
     pub paused_since_block: Option<u64>,
     pub denom: Option<String>,
+    // none for Osmosis
+    pub tokenfactory_dialect: Option<TokenfactoryDialect>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
+    // NOTE `asset` is the denom of the asset, none for the default asset
+
     // user level methods
     Swap {
         destination: String,
+        asset: Option<String>,
+    },
+
+    // pays out the amounts held for the sender
+    Claim {},
+
+    // pays out the amounts held for `address` to it, by anyone
+    ClaimFor {
+        address: Addr,
+    },
+
+    // pays out what is held for `address` in the asset to `to`, by `address` or an admin
+    // (timelocked then)
+    RedirectClaimable {
+        address: Addr,
+        to: Addr,
+        asset: Option<String>,
+    },
+
+    // pays out a payout held for review to its recipient, or to `to` (timelocked then)
+    ReleaseHeldPayout {
+        operation: String,
+        id: u64,
+        to: Option<Addr>,
     },
 
     // relayer
@@ -43,13 +82,16 @@ pub enum ExecuteMsg {
         origin_tx_hash: String, // TOD(LR) should be [32]u8 or String
         amount: Uint128,
         relay_eon: u64,
+        asset: Option<String>,
     },
 
+    // the asset of a refund is the one of the swap, `asset` is only checked against it
     Refund {
         id: u64,
         to: Addr,
         amount: Uint128,
         relay_eon: u64,
+        asset: Option<String>,
     },
 
     RefundInFull {
@@ -57,49 +99,108 @@ pub enum ExecuteMsg {
         to: Addr,
         amount: Uint128,
         relay_eon: u64,
+        asset: Option<String>,
     },
 
+    // none pauses the whole contract, not the default asset
     PausePublicApi {
         since_block: u64,
+        asset: Option<String>,
     },
 
     PauseRelayerApi {
         since_block: u64,
+        asset: Option<String>,
+    },
+
+    // pauses a single operation, the two above pause every operation of their API
+    Pause {
+        operation: PausableOperation,
+        since: PauseStart,
+        reason: Option<String>,
+    },
+
+    // an admin keeps a monitor's pause from lapsing
+    ConfirmPause {
+        operation: PausableOperation,
+    },
+
+    // none lets monitor pauses last until an admin unpauses
+    SetMonitorPauseMax {
+        seconds: Option<u64>,
+    },
+
+    // monitors may only block, by adding to the blocklist or removing from the allowlist
+    UpdateAddressList {
+        list: AddressList,
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+
+    // only the addresses of the allowlist (and not blocked) are let through once enabled
+    SetAllowlistEnabled {
+        enabled: bool,
     },
 
     NewRelayEon {},
 
+    // CW20 tokens sent along a `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+
     // admin
-    Deposit {},
+    AddAsset {
+        denom: String,
+        // none for a native token
+        kind: Option<AssetKind>,
+        cap: Uint128,
+        upper_swap_limit: Uint128,
+        lower_swap_limit: Uint128,
+        swap_fee: Uint128,
+        reverse_aggregated_allowance: Uint128,
+        reverse_aggregated_allowance_approver_cap: Uint128,
+        paused_since_block: Option<u64>,
+    },
+
+    Deposit {
+        asset: Option<String>,
+    },
 
     Withdraw {
         // withdrawal from contract supply to destination
         amount: Uint128,
         destination: Addr,
+        asset: Option<String>,
     },
 
     WithdrawFees {
         // withdrawal from contract (account - supply) to destination
         amount: Uint128,
         destination: Addr,
+        asset: Option<String>,
     },
 
     SetCap {
         amount: Uint128,
+        asset: Option<String>,
     },
 
     SetReverseAggregatedAllowance {
         amount: Uint128,
+        asset: Option<String>,
     },
 
     SetReverseAggregatedAllowanceApproverCap {
         amount: Uint128,
+        asset: Option<String>,
     },
 
     SetLimits {
         swap_min: Uint128,
         swap_max: Uint128,
         swap_fee: Uint128,
+        // none charges the flat `swap_fee`
+        fee_policy: Option<FeePolicy>,
+        asset: Option<String>,
     },
 
     // Access Control
@@ -117,28 +218,209 @@ pub enum ExecuteMsg {
         role: String,
     },
 
+    SetReverseSwapIdOrdering {
+        ordering: ReverseSwapIdOrdering,
+    },
+
     Mint {
         amount: Uint128,
+        asset: Option<String>,
     },
 
     Burn {
         amount: Uint128,
+        asset: Option<String>,
+    },
+
+    ProposeAdmin {
+        address: Addr,
+        expires_at_block: Option<u64>,
+    },
+
+    AcceptAdmin {},
+
+    CancelAdminProposal {},
+
+    // timelock
+    SetTimelockDelay {
+        blocks: u64,
+    },
+
+    ExecuteQueued {
+        id: u64,
+    },
+
+    CancelQueued {
+        id: u64,
+    },
+
+    SetRelayerThreshold {
+        threshold: u32,
+    },
+
+    // drops the pending attestations of a transfer, for relayers to attest it anew
+    ClearAttestations {
+        kind: AttestationKind,
+        id: u64,
+    },
+
+    SetOutflowLimit {
+        // none disables the rate limit
+        limit: Option<OutflowLimit>,
+        asset: Option<String>,
     },
+
+    SetSenderLimits {
+        // none disables the per sender limits
+        limits: Option<SenderLimits>,
+        asset: Option<String>,
+    },
+
+    SetDustPolicy {
+        policy: DustPolicy,
+    },
+
+    // only while no native asset has supply outstanding
+    SetCustodyMode {
+        mode: CustodyMode,
+    },
+
+    SetPayoutMode {
+        mode: PayoutMode,
+    },
+
+    // tokenfactory, `factory/<contract>/<subdenom>` becomes the bridge denom
+    CreateDenom {
+        subdenom: String,
+    },
+
+    ChangeDenomAdmin {
+        new_admin: Addr,
+        asset: Option<String>,
+    },
+
+    // for the asset of `metadata.base`
+    SetDenomMetadata {
+        metadata: DenomMetadata,
+    },
+}
+
+// messages embedded in the CW20 `Send` to the contract, for the asset of the CW20 contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiveMsg {
+    Swap { destination: String },
+
+    Deposit {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    HasRole { role: String, address: Addr },
+    HasRole {
+        role: String,
+        address: Addr,
+    },
     RelayEon {},
-    Supply {},
-    ReverseAggregatedAllowance {},
-    SwapMax {},
-    Cap {},
-    PausedPublicApiSince {},
-    PausedRelayerApiSince {},
+    // NOTE `asset` is the denom of the asset, none for the default asset
+    Supply {
+        asset: Option<String>,
+    },
+    ReverseAggregatedAllowance {
+        asset: Option<String>,
+    },
+    SwapMax {
+        asset: Option<String>,
+    },
+    Cap {
+        asset: Option<String>,
+    },
+    // none for the pause of the whole contract
+    PausedPublicApiSince {
+        asset: Option<String>,
+    },
+    PausedRelayerApiSince {
+        asset: Option<String>,
+    },
+    // pauses of the whole contract
+    // with the pauses of `asset` when given
+    PauseStatus {
+        asset: Option<String>,
+    },
     Denom {},
     FullState {},
+    Asset {
+        denom: String,
+    },
+    ListAssets {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    ReverseSwapProcessed {
+        rid: u64,
+    },
+    ReverseSwapIdSeal {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    Swap {
+        id: u64,
+    },
+    Refund {
+        id: u64,
+    },
+    ListSwaps {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        sender: Option<Addr>,
+    },
+    ListRoleMembers {
+        role: String,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+    RolesOf {
+        address: Addr,
+    },
+    PendingAdmin {},
+    PendingOperations {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    PendingAttestations {
+        kind: AttestationKind,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    OutflowCapacity {
+        asset: Option<String>,
+    },
+    SenderUsage {
+        address: Addr,
+        asset: Option<String>,
+    },
+    DustPolicy {},
+    CustodyMode {},
+    PayoutMode {},
+    TokenfactoryDialect {},
+    Claimable {
+        address: Addr,
+    },
+    // amounts held for every address, by (address, denom)
+    ListClaimable {
+        start_after: Option<(Addr, String)>,
+        limit: Option<u32>,
+    },
+    AddressList {
+        list: AddressList,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // payouts held for review, by (operation, id)
+    HeldPayouts {
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    },
 }
 
 // We define a custom struct for each query response
@@ -147,6 +429,77 @@ pub struct RoleResponse {
     pub has_role: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListRoleMembersResponse {
+    pub members: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RolesOfResponse {
+    pub roles: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingAdminResponse {
+    pub pending_admin: Option<PendingAdmin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingOperationsResponse {
+    pub timelock_delay: u64,
+    pub operations: Vec<QueuedOperation>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingAttestation {
+    pub id: u64,
+    pub attestations: Vec<Attestation>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingAttestationsResponse {
+    pub relayer_threshold: u32,
+    pub pending: Vec<PendingAttestation>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OutflowCapacityResponse {
+    pub limit: Option<OutflowLimit>,
+    // none when no limit is set
+    pub available: Option<Uint128>,
+    // block height or seconds (depending on the window) at which capacity is back to max
+    pub full_at: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SenderUsageResponse {
+    pub limits: Option<SenderLimits>,
+    pub volume: Uint128,
+    pub swaps: u32,
+    // block height or seconds (depending on the window) at which usage resets
+    pub resets_at: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DustPolicyResponse {
+    pub policy: DustPolicy,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CustodyModeResponse {
+    pub mode: CustodyMode,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayoutModeResponse {
+    pub mode: PayoutMode,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TokenfactoryDialectResponse {
+    pub dialect: TokenfactoryDialect,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct RelayEonResponse {
     pub eon: u64,
@@ -161,12 +514,127 @@ pub type CapResponse = SupplyResponse;
 pub type SwapMaxResponse = SupplyResponse;
 pub type ReverseAggregatedAllowanceResponse = SupplyResponse;
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimableResponse {
+    pub claimable: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimableBalance {
+    pub address: Addr,
+    pub denom: String,
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListClaimableResponse {
+    pub claimable: Vec<ClaimableBalance>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddressListResponse {
+    pub allowlist_enabled: bool,
+    pub addresses: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HeldPayoutsResponse {
+    pub held: Vec<Payout>,
+}
+
+pub type AssetResponse = Asset;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListAssetsResponse {
+    pub assets: Vec<Asset>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct PausedSinceBlockResponse {
     pub block: u64,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseStatus {
+    pub since_block: u64,
+    // at the current block
+    pub paused: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperationPauseStatus {
+    pub operation: PausableOperation,
+    // kept once lapsed
+    pub pause: Option<OperationPause>,
+    // by the operation, or the pause of the asset queried
+    pub paused: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AssetPauseStatus {
+    pub denom: String,
+    pub public_api: PauseStatus,
+    pub relayer_api: PauseStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseStatusResponse {
+    pub public_api: PauseStatus,
+    pub relayer_api: PauseStatus,
+    pub operations: Vec<OperationPauseStatus>,
+    pub monitor_pause_max: Option<u64>,
+    pub asset: Option<AssetPauseStatus>,
+}
+
+// state as laid out before assets, the configuration and accounting being those of the
+// default asset and the pauses those of the APIs
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FullStateResponse {
+    pub supply: Uint128,
+    pub fees_accrued: Uint128,
+    pub next_swap_id: u64,
+    pub sealed_reverse_swap_id: u64,
+    pub relay_eon: u64,
+    pub upper_swap_limit: Uint128,
+    pub lower_swap_limit: Uint128,
+    pub reverse_aggregated_allowance: Uint128,
+    pub reverse_aggregated_allowance_approver_cap: Uint128,
+    pub cap: Uint128,
+    pub swap_fee: Uint128,
+    pub paused_since_block_public_api: u64,
+    pub paused_since_block_relayer_api: u64,
+    pub denom: String,
+    pub contract_addr_human: Addr,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct DenomResponse {
     pub denom: String,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReverseSwapProcessedResponse {
+    pub processed: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReverseSwapIdSealResponse {
+    pub sealed_reverse_swap_id: u64,
+    pub relay_eon: u64,
+    pub ordering: ReverseSwapIdOrdering,
+    pub gaps: Vec<ReverseSwapIdGap>,
+}
+
+pub type SwapResponse = SwapRecord;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListSwapsResponse {
+    pub swaps: Vec<SwapRecord>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RefundResponse {
+    pub refunded: bool,
+    // None for refunds processed before amounts were recorded
+    pub refund: Option<RefundRecord>,
+}
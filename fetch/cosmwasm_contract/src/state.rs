@@ -3,37 +3,738 @@ use serde::{Deserialize, Serialize};
 
 use crate::msg::Uint128;
 use cosmwasm_std::storage_keys::to_length_prefixed;
-use cosmwasm_std::{Addr, Storage};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Env, StdResult, Storage, Timestamp, from_json, to_json_vec};
+use cw_storage_plus::{Item, Map};
+use std::fmt;
+
+use crate::error::ContractError;
 
 // To keep backwards compatibility with cosmwasm_storage::singleton
 // we must use the same length-prefixed key format.
 pub static CONFIG_KEY: &str = "\u{0}\u{6}config";
 pub static REFUNDS_KEY: &str = "refunds";
+pub static REVERSE_SWAPS_KEY: &str = "reverse_swaps";
+pub static REVERSE_SWAP_ID_ORDERING_KEY: &str = "reverse_swap_id_ordering";
+pub static REVERSE_SWAP_ID_GAPS_KEY: &str = "reverse_swap_id_gaps";
+pub static SWAPS_KEY: &str = "swaps";
+pub static SWAPS_BY_SENDER_KEY: &str = "swaps_by_sender";
+pub static TIMELOCK_DELAY_KEY: &str = "timelock_delay";
+pub static QUEUED_OPERATIONS_KEY: &str = "queued_operations";
+pub static NEXT_QUEUED_OPERATION_ID_KEY: &str = "next_queued_operation_id";
+pub static RELAYER_THRESHOLD_KEY: &str = "relayer_threshold";
+pub static ATTESTATIONS_KEY: &str = "attestations";
+pub static OUTFLOW_LIMIT_KEY: &str = "outflow_limit";
+pub static OUTFLOW_USAGE_KEY: &str = "outflow_usage";
+pub static SENDER_LIMITS_KEY: &str = "sender_limits";
+pub static SENDER_USAGE_KEY: &str = "sender_usage";
+pub static DUST_POLICY_KEY: &str = "dust_policy";
+pub static CLAIMABLE_KEY: &str = "claimable";
+pub static ASSETS_KEY: &str = "assets";
+pub static DENOM_RENAMES_KEY: &str = "denom_renames";
+pub static CUSTODY_MODE_KEY: &str = "custody_mode";
+pub static TOKENFACTORY_DIALECT_KEY: &str = "tokenfactory_dialect";
+pub static PAYOUT_MODE_KEY: &str = "payout_mode";
+pub static PAUSES_KEY: &str = "pauses";
+pub static MONITOR_PAUSE_MAX_KEY: &str = "monitor_pause_max";
+pub static ADDRESS_LISTS_KEY: &str = "address_lists";
+pub static ALLOWLIST_ENABLED_KEY: &str = "allowlist_enabled";
+pub static HELD_PAYOUTS_KEY: &str = "held_payouts";
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct State {
-    pub supply: Uint128,       // amount of token migrated to the other chain
-    pub fees_accrued: Uint128, // fees
     pub next_swap_id: u64,
+    // one past the highest rid processed, the rids skipped below it are recorded as gaps
+    // while an ordering policy is enforced (not those skipped while unordered)
     pub sealed_reverse_swap_id: u64,
     pub relay_eon: u64,
+    pub denom: String, // default asset, used by messages not naming one
+}
+
+pub const CONFIG: Item<State> = Item::new(CONFIG_KEY);
+
+/// Kind of token of an asset, the denom of a CW20 asset is the address of its contract
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetKind {
+    Native,
+    Cw20,
+}
+
+/// Bridged token, with its own configuration and accounting
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Asset {
+    pub denom: String,
+    pub kind: AssetKind,
+    pub supply: Uint128,       // amount of token migrated to the other chain
+    pub fees_accrued: Uint128, // fees
+    pub cap: Uint128,
     pub upper_swap_limit: Uint128,
     pub lower_swap_limit: Uint128,
+    pub swap_fee: Uint128, // flat fee, used when no `fee_policy` is set
+    pub fee_policy: Option<FeePolicy>,
     pub reverse_aggregated_allowance: Uint128,
     pub reverse_aggregated_allowance_approver_cap: Uint128,
-    pub cap: Uint128,
-    pub swap_fee: Uint128,
     pub paused_since_block_public_api: u64,
     pub paused_since_block_relayer_api: u64,
+}
+
+pub const ASSETS: Map<&str, Asset> = Map::new(ASSETS_KEY);
+
+/// Previous denoms of the default asset, to the denom that replaced them
+pub const DENOM_RENAMES: Map<&str, String> = Map::new(DENOM_RENAMES_KEY);
+
+// Denom the asset once keyed by `denom` is now known by (swaps keep their original denom)
+pub fn renamed_denom(storage: &dyn Storage, denom: &str) -> StdResult<String> {
+    let mut denom = denom.to_string();
+    while let Some(renamed) = DENOM_RENAMES.may_load(storage, &denom)? {
+        denom = renamed;
+    }
+    Ok(denom)
+}
+
+impl Asset {
+    pub fn fee_policy(&self) -> FeePolicy {
+        self.fee_policy
+            .clone()
+            .unwrap_or(FeePolicy::Flat { fee: self.swap_fee })
+    }
+
+    /// Fee charged on a reverse swap or refund of `amount`
+    pub fn fee_for(&self, amount: Uint128) -> Uint128 {
+        match &self.fee_policy {
+            Some(policy) => policy.fee(amount),
+            None => self.swap_fee,
+        }
+    }
+}
+
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// Fee deducted from reverse swaps and refunds
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeePolicy {
+    // same fee whatever the amount
+    Flat {
+        fee: Uint128,
+    },
+    // `bps` basis points of the amount, clamped to [min_fee, max_fee]
+    BasisPoints {
+        bps: u16,
+        min_fee: Uint128,
+        max_fee: Option<Uint128>,
+    },
+    // flat fee of the tier with the highest `min_amount` <= amount
+    Tiered {
+        tiers: Vec<FeeTier>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeTier {
+    pub min_amount: Uint128,
+    pub fee: Uint128,
+}
+
+impl FeePolicy {
+    pub fn fee(&self, amount: Uint128) -> Uint128 {
+        match self {
+            FeePolicy::Flat { fee } => *fee,
+            FeePolicy::BasisPoints {
+                bps,
+                min_fee,
+                max_fee,
+            } => {
+                let fee = amount.multiply_ratio(*bps, MAX_FEE_BPS).max(*min_fee);
+                max_fee.map_or(fee, |max_fee| fee.min(max_fee))
+            }
+            FeePolicy::Tiered { tiers } => tiers
+                .iter()
+                .rev()
+                .find(|tier| tier.min_amount <= amount)
+                .map_or(Uint128::zero(), |tier| tier.fee),
+        }
+    }
+
+    /// Checks the policy itself: bps within 100%, min_fee <= max_fee, tiers
+    /// starting at 0 and sorted by strictly increasing `min_amount`
+    pub fn verify(&self) -> Result<(), ContractError> {
+        let valid = match self {
+            FeePolicy::Flat { .. } => true,
+            FeePolicy::BasisPoints {
+                bps,
+                min_fee,
+                max_fee,
+            } => *bps <= MAX_FEE_BPS && max_fee.is_none_or(|max_fee| *min_fee <= max_fee),
+            FeePolicy::Tiered { tiers } => {
+                tiers.first().is_some_and(|tier| tier.min_amount.is_zero())
+                    && tiers
+                        .windows(2)
+                        .all(|pair| pair[0].min_amount < pair[1].min_amount)
+            }
+        };
+        if !valid {
+            return Err(ContractError::InvalidFeePolicy {});
+        }
+        Ok(())
+    }
+
+    /// Checks that every amount within [swap_min, swap_max] is worth more than
+    /// its fee, `swap_min <= swap_max` included
+    pub fn verify_limits(&self, swap_min: Uint128, swap_max: Uint128) -> Result<(), ContractError> {
+        self.verify()?;
+        if swap_min > swap_max {
+            return Err(ContractError::SwapLimitsInconsistent {});
+        }
+
+        // amount - fee never decreases within a tier (bps <= 100%), so checking
+        // the lowest amount of each tier within the limits is enough
+        let mut amounts = vec![swap_min];
+        if let FeePolicy::Tiered { tiers } = self {
+            amounts.extend(
+                tiers
+                    .iter()
+                    .map(|tier| tier.min_amount)
+                    .filter(|amount| *amount > swap_min && *amount <= swap_max),
+            );
+        }
+        if amounts.into_iter().any(|amount| amount <= self.fee(amount)) {
+            return Err(ContractError::SwapLimitsInconsistent {});
+        }
+        Ok(())
+    }
+}
+
+/// Ordering policy enforced on incoming reverse swap ids. Rids number the swaps of the
+/// other chain and go on across relay eons, the policy holds over the whole sequence.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReverseSwapIdOrdering {
+    // any not yet processed rid is accepted
+    Unordered,
+    // rid must be >= sealed_reverse_swap_id, skipped ids are recorded as gaps
+    StrictlyIncreasing,
+    // same as StrictlyIncreasing, but at most `max_gap` ids can be skipped at once
+    Contiguous { max_gap: u64 },
+}
+
+/// Range of reverse swap ids [start, end) skipped when sealing `end`, in relay eon `relay_eon`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReverseSwapIdGap {
+    pub start: u64,
+    pub end: u64,
+    pub relay_eon: u64,
+}
+
+pub const REVERSE_SWAP_ID_ORDERING: Item<ReverseSwapIdOrdering> =
+    Item::new(REVERSE_SWAP_ID_ORDERING_KEY);
+pub const REVERSE_SWAP_ID_GAPS: Map<u64, ReverseSwapIdGap> = Map::new(REVERSE_SWAP_ID_GAPS_KEY);
+
+/// Outbound swap, as accepted by `try_swap`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SwapRecord {
+    pub id: u64,
     pub denom: String,
+    pub sender: Addr,
+    pub destination: String,
+    pub amount: Uint128,
+    pub block_height: u64,
+    pub time: Timestamp,
+}
 
-    // optimization FIXME(LR) Not needed any more with version 0.10.0
-    pub contract_addr_human: Addr,
+pub const SWAPS: Map<u64, SwapRecord> = Map::new(SWAPS_KEY);
+// secondary index (sender, swap id) used to list swaps of a given sender
+pub const SWAPS_BY_SENDER: Map<(&Addr, u64), ()> = Map::new(SWAPS_BY_SENDER_KEY);
+
+pub fn swaps_add(storage: &mut dyn Storage, swap: &SwapRecord) -> StdResult<()> {
+    SWAPS.save(storage, swap.id, swap)?;
+    SWAPS_BY_SENDER.save(storage, (&swap.sender, swap.id), &())
 }
 
-pub const CONFIG: Item<State> = Item::new(CONFIG_KEY);
+pub fn reverse_swap_id_ordering(storage: &dyn Storage) -> StdResult<ReverseSwapIdOrdering> {
+    Ok(REVERSE_SWAP_ID_ORDERING
+        .may_load(storage)?
+        .unwrap_or(ReverseSwapIdOrdering::Unordered))
+}
+
+/// Admin operations held back by the timelock
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelockedOperation {
+    Withdraw {
+        amount: Uint128,
+        destination: Addr,
+        asset: Option<String>,
+    },
+    WithdrawFees {
+        amount: Uint128,
+        destination: Addr,
+        asset: Option<String>,
+    },
+    SetCap {
+        amount: Uint128,
+        asset: Option<String>,
+    },
+    SetLimits {
+        swap_min: Uint128,
+        swap_max: Uint128,
+        swap_fee: Uint128,
+        fee_policy: Option<FeePolicy>,
+        asset: Option<String>,
+    },
+    GrantRole {
+        role: String,
+        address: Addr,
+    },
+    RevokeRole {
+        role: String,
+        address: Addr,
+    },
+    ProposeAdmin {
+        address: Addr,
+        expires_at_block: Option<u64>,
+    },
+    UnpausePublicApi {
+        since_block: u64,
+        asset: Option<String>,
+    },
+    UnpauseRelayerApi {
+        since_block: u64,
+        asset: Option<String>,
+    },
+    Unpause {
+        operation: PausableOperation,
+        since: PauseStart,
+        reason: Option<String>,
+    },
+    SetTimelockDelay {
+        blocks: u64,
+    },
+    SetRelayerThreshold {
+        threshold: u32,
+    },
+    RedirectClaimable {
+        address: Addr,
+        to: Addr,
+        asset: Option<String>,
+    },
+    CreateDenom {
+        subdenom: String,
+    },
+    ChangeDenomAdmin {
+        new_admin: Addr,
+        asset: Option<String>,
+    },
+    ReleaseHeldPayout {
+        operation: String,
+        id: u64,
+        to: Addr,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QueuedOperation {
+    pub id: u64,
+    pub operation: TimelockedOperation,
+    pub proposer: Addr,
+    pub queued_at_block: u64,
+    pub execute_at_block: u64,
+}
+
+// delay in blocks, 0 (default) disables the timelock
+pub const TIMELOCK_DELAY: Item<u64> = Item::new(TIMELOCK_DELAY_KEY);
+pub const QUEUED_OPERATIONS: Map<u64, QueuedOperation> = Map::new(QUEUED_OPERATIONS_KEY);
+pub const NEXT_QUEUED_OPERATION_ID: Item<u64> = Item::new(NEXT_QUEUED_OPERATION_ID_KEY);
+
+pub fn timelock_delay(storage: &dyn Storage) -> StdResult<u64> {
+    Ok(TIMELOCK_DELAY.may_load(storage)?.unwrap_or(0))
+}
+
+/// Relayer operations paying out once attested by enough relayers
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttestationKind {
+    ReverseSwap,
+    Refund,
+    RefundInFull,
+}
+
+impl AttestationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttestationKind::ReverseSwap => "reverse_swap",
+            AttestationKind::Refund => "refund",
+            AttestationKind::RefundInFull => "refund_in_full",
+        }
+    }
+}
+
+/// Transfer attested by `relayers`, identical attestations are tallied together
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Attestation {
+    pub denom: String,
+    pub to: Addr,
+    pub amount: Uint128,
+    // none for refunds
+    pub origin_tx_hash: Option<String>,
+    pub relay_eon: u64,
+    pub relayers: Vec<Addr>,
+}
+
+impl Attestation {
+    pub fn same_transfer(&self, other: &Attestation) -> bool {
+        self.denom == other.denom
+            && self.to == other.to
+            && self.amount == other.amount
+            && self.origin_tx_hash == other.origin_tx_hash
+            && self.relay_eon == other.relay_eon
+    }
+}
+
+// number of identical attestations needed to pay out, 1 (default) lets a single relayer act
+pub const RELAYER_THRESHOLD: Item<u32> = Item::new(RELAYER_THRESHOLD_KEY);
+// (kind, rid or swap id) -> distinct transfers attested so far
+pub const ATTESTATIONS: Map<(&str, u64), Vec<Attestation>> = Map::new(ATTESTATIONS_KEY);
+
+pub fn relayer_threshold(storage: &dyn Storage) -> StdResult<u32> {
+    Ok(RELAYER_THRESHOLD.may_load(storage)?.unwrap_or(1))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RateWindow {
+    Blocks(u64),
+    Seconds(u64),
+}
+
+impl RateWindow {
+    pub fn period(&self) -> u64 {
+        match *self {
+            RateWindow::Blocks(blocks) => blocks,
+            RateWindow::Seconds(seconds) => seconds,
+        }
+    }
+
+    // current block height or time in seconds, depending on the window
+    pub fn now(&self, env: &Env) -> u64 {
+        match self {
+            RateWindow::Blocks(_) => env.block.height,
+            RateWindow::Seconds(_) => env.block.time.seconds(),
+        }
+    }
+}
+
+/// Rate limit on reverse swap and refund outflow: up to `max_outflow` per `window`,
+/// capacity refills linearly over the window
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OutflowLimit {
+    pub max_outflow: Uint128,
+    pub window: RateWindow,
+}
+
+/// Capacity left at `updated_at` (block height or seconds, depending on the window)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OutflowUsage {
+    pub available: Uint128,
+    pub updated_at: u64,
+}
+
+impl OutflowLimit {
+    pub fn period(&self) -> u64 {
+        self.window.period()
+    }
+
+    pub fn now(&self, env: &Env) -> u64 {
+        self.window.now(env)
+    }
+
+    pub fn available(&self, usage: Option<&OutflowUsage>, now: u64) -> Uint128 {
+        match usage {
+            None => self.max_outflow,
+            Some(usage) => {
+                let elapsed = now.saturating_sub(usage.updated_at);
+                let refill = self.max_outflow.multiply_ratio(elapsed, self.period());
+                usage.available.saturating_add(refill).min(self.max_outflow)
+            }
+        }
+    }
+
+    // when the capacity is fully refilled
+    pub fn full_at(&self, available: Uint128, now: u64) -> u64 {
+        let missing = self.max_outflow.saturating_sub(available);
+        if missing.is_zero() {
+            return now;
+        }
+        let wait = missing
+            .checked_mul_ceil((Uint128::from(self.period()), self.max_outflow))
+            .unwrap_or(Uint128::from(u64::MAX));
+        now.saturating_add(u64::try_from(wait.u128()).unwrap_or(u64::MAX))
+    }
+}
+
+// per asset, not set (default): no rate limit
+pub const OUTFLOW_LIMIT: Map<&str, OutflowLimit> = Map::new(OUTFLOW_LIMIT_KEY);
+pub const OUTFLOW_USAGE: Map<&str, OutflowUsage> = Map::new(OUTFLOW_USAGE_KEY);
+
+/// Limits on public swaps per sender within a fixed `window`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SenderLimits {
+    pub window: RateWindow,
+    pub max_volume: Option<Uint128>,
+    pub max_swaps: Option<u32>,
+}
+
+/// Swaps of a sender in the window started at `window_start`
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct SenderUsage {
+    pub window_start: u64,
+    pub volume: Uint128,
+    pub swaps: u32,
+}
+
+// per asset, not set (default): no per sender limits
+pub const SENDER_LIMITS: Map<&str, SenderLimits> = Map::new(SENDER_LIMITS_KEY);
+pub const SENDER_USAGE: Map<(&str, &Addr), SenderUsage> = Map::new(SENDER_USAGE_KEY);
+
+/// Usage of `sender` in the current window
+pub fn sender_usage(
+    storage: &dyn Storage,
+    denom: &str,
+    limits: &SenderLimits,
+    sender: &Addr,
+    now: u64,
+) -> StdResult<SenderUsage> {
+    match SENDER_USAGE.may_load(storage, (denom, sender))? {
+        Some(usage) if now < usage.window_start.saturating_add(limits.window.period()) => Ok(usage),
+        _ => Ok(SenderUsage {
+            window_start: now,
+            ..SenderUsage::default()
+        }),
+    }
+}
+
+/// Handling of reverse swaps and refunds whose amount does not exceed the fee
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DustPolicy {
+    // the call fails, nothing is processed
+    Reject,
+    // the whole amount is credited to the recipient, to be claimed later
+    Hold,
+    // the whole amount is taken as fee
+    Confiscate,
+}
+
+pub const DUST_POLICY: Item<DustPolicy> = Item::new(DUST_POLICY_KEY);
+
+// defaults to the historical behaviour
+pub fn dust_policy(storage: &dyn Storage) -> StdResult<DustPolicy> {
+    Ok(DUST_POLICY
+        .may_load(storage)?
+        .unwrap_or(DustPolicy::Confiscate))
+}
+
+/// How the contract holds the native assets it bridges, CW20 assets are always locked
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustodyMode {
+    // swapped tokens are locked in the contract and released back,
+    // `supply` is the amount locked
+    Lock,
+    // swapped tokens are burnt and paid out by minting (tokenfactory),
+    // `supply` is the amount issued by the contract
+    MintBurn,
+}
+
+pub const CUSTODY_MODE: Item<CustodyMode> = Item::new(CUSTODY_MODE_KEY);
+
+pub fn custody_mode(storage: &dyn Storage) -> StdResult<CustodyMode> {
+    Ok(CUSTODY_MODE.may_load(storage)?.unwrap_or(CustodyMode::Lock))
+}
+
+/// Flavour of the tokenfactory module of the chain, setting the type urls and the layout
+/// of the messages the contract sends to it
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenfactoryDialect {
+    // `osmosis.tokenfactory.v1beta1`, also the package registered by Neutron and by the
+    // standalone Cosmos SDK `x/tokenfactory` module (strangelove-ventures/tokenfactory)
+    Osmosis,
+    // `injective.tokenfactory.v1beta1`, same messages under another type url
+    Injective,
+}
+
+pub const TOKENFACTORY_DIALECT: Item<TokenfactoryDialect> = Item::new(TOKENFACTORY_DIALECT_KEY);
+
+// contracts instantiated before dialects were configurable run on Osmosis
+pub fn tokenfactory_dialect(storage: &dyn Storage) -> StdResult<TokenfactoryDialect> {
+    Ok(TOKENFACTORY_DIALECT
+        .may_load(storage)?
+        .unwrap_or(TokenfactoryDialect::Osmosis))
+}
+
+/// Operations that can be paused on their own, pausing an API pauses the operations it
+/// groups (public API: swap, relayer API: reverse swap and refund)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PausableOperation {
+    Swap,
+    ReverseSwap,
+    Refund,
+    Deposit,
+    // withdrawals of supply and of fees
+    Withdraw,
+    MintBurn,
+}
+
+impl PausableOperation {
+    pub const ALL: [PausableOperation; 6] = [
+        PausableOperation::Swap,
+        PausableOperation::ReverseSwap,
+        PausableOperation::Refund,
+        PausableOperation::Deposit,
+        PausableOperation::Withdraw,
+        PausableOperation::MintBurn,
+    ];
+
+    pub const PUBLIC_API: [PausableOperation; 1] = [PausableOperation::Swap];
+
+    pub const RELAYER_API: [PausableOperation; 2] =
+        [PausableOperation::ReverseSwap, PausableOperation::Refund];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PausableOperation::Swap => "swap",
+            PausableOperation::ReverseSwap => "reverse_swap",
+            PausableOperation::Refund => "refund",
+            PausableOperation::Deposit => "deposit",
+            PausableOperation::Withdraw => "withdraw",
+            PausableOperation::MintBurn => "mint_burn",
+        }
+    }
+}
+
+/// Point from which a pause applies, a pause starting in the future unpauses until then
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseStart {
+    Height(u64),
+    Time(Timestamp),
+}
+
+impl PauseStart {
+    pub fn reached(&self, env: &Env) -> bool {
+        match self {
+            PauseStart::Height(height) => env.block.height >= *height,
+            PauseStart::Time(time) => env.block.time >= *time,
+        }
+    }
+}
+
+impl fmt::Display for PauseStart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PauseStart::Height(height) => write!(f, "block {height}"),
+            PauseStart::Time(time) => write!(f, "time {}", time.seconds()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperationPause {
+    pub since: PauseStart,
+    pub reason: String,
+    pub paused_by: Addr,
+    // pauses by monitors lapse then, unless confirmed by an admin
+    pub expires_at: Option<Timestamp>,
+}
+
+impl OperationPause {
+    pub fn active(&self, env: &Env) -> bool {
+        self.since.reached(env)
+            && self
+                .expires_at
+                .is_none_or(|expires_at| env.block.time < expires_at)
+    }
+}
+
+/// Pause of each operation, by operation name
+pub const PAUSES: Map<&str, OperationPause> = Map::new(PAUSES_KEY);
+
+/// Longest pause (in seconds) a monitor can set without an admin confirming it,
+/// none for pauses lasting until an admin unpauses
+pub const MONITOR_PAUSE_MAX: Item<u64> = Item::new(MONITOR_PAUSE_MAX_KEY);
+
+/// Amounts owed by the contract per (address, denom), paid out with `Claim {}`
+pub const CLAIMABLE: Map<(&Addr, &str), Uint128> = Map::new(CLAIMABLE_KEY);
+
+/// How reverse swaps and refunds are delivered to their recipient
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMode {
+    // sent to the recipient along the operation
+    Push,
+    // credited to the recipient, to be claimed
+    Pull,
+}
+
+pub const PAYOUT_MODE: Item<PayoutMode> = Item::new(PAYOUT_MODE_KEY);
+
+pub fn payout_mode(storage: &dyn Storage) -> StdResult<PayoutMode> {
+    Ok(PAYOUT_MODE.may_load(storage)?.unwrap_or(PayoutMode::Push))
+}
+
+/// Payout of a reverse swap or refund, carried as the payload of its submessage
+/// to be credited to `to` should it fail
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Payout {
+    pub operation: String,
+    pub id: u64,
+    pub to: Addr,
+    pub denom: String,
+    pub amount: Uint128,
+}
+
+/// Payouts of reverse swaps and refunds to addresses not allowed, held for an admin to
+/// review, by (operation, rid or swap id)
+pub const HELD_PAYOUTS: Map<(&str, u64), Payout> = Map::new(HELD_PAYOUTS_KEY);
+
+/// Lists of addresses, Cosmos or Ethereum, checked against the parties of swaps and payouts
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressList {
+    // never allowed
+    Blocked,
+    // the only ones allowed, when the allowlist is enabled
+    Allowed,
+}
+
+impl AddressList {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AddressList::Blocked => "blocked",
+            AddressList::Allowed => "allowed",
+        }
+    }
+}
+
+// (list name, address)
+pub const ADDRESS_LISTS: Map<(&str, &str), ()> = Map::new(ADDRESS_LISTS_KEY);
+pub const ALLOWLIST_ENABLED: Item<bool> = Item::new(ALLOWLIST_ENABLED_KEY);
+
+pub fn allowlist_enabled(storage: &dyn Storage) -> StdResult<bool> {
+    Ok(ALLOWLIST_ENABLED.may_load(storage)?.unwrap_or(false))
+}
+
+pub fn address_allowed(storage: &dyn Storage, address: &str) -> StdResult<bool> {
+    if ADDRESS_LISTS.has(storage, (AddressList::Blocked.name(), address)) {
+        return Ok(false);
+    }
+    Ok(!allowlist_enabled(storage)?
+        || ADDRESS_LISTS.has(storage, (AddressList::Allowed.name(), address)))
+}
 
 fn refunds_key(swap_id: u64) -> Vec<u8> {
     let mut k = to_length_prefixed(REFUNDS_KEY.as_bytes());
@@ -41,12 +742,52 @@ fn refunds_key(swap_id: u64) -> Vec<u8> {
     k
 }
 
-pub fn refunds_add(swap_id: u64, storage: &mut dyn Storage) {
+/// Refund paid for a swap, `amount` being the part of the original swap refunded
+/// (fee included) and `fee` the part kept by the contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RefundRecord {
+    pub amount: Uint128,
+    pub fee: Uint128,
+}
+
+pub fn refunds_add(
+    swap_id: u64,
+    refund: &RefundRecord,
+    storage: &mut dyn Storage,
+) -> StdResult<()> {
     let key = refunds_key(swap_id);
-    storage.set(&key, &[1]);
+    storage.set(&key, &to_json_vec(refund)?);
+    Ok(())
+}
+
+/// Refunds processed before amounts were recorded only hold a `[1]` marker,
+/// for those `Some(None)` is returned
+pub fn refunds_get(swap_id: u64, storage: &dyn Storage) -> StdResult<Option<Option<RefundRecord>>> {
+    let key = refunds_key(swap_id);
+    match storage.get(&key) {
+        None => Ok(None),
+        Some(data) if data == [1] => Ok(Some(None)),
+        Some(data) => Ok(Some(Some(from_json(&data)?))),
+    }
 }
 
 pub fn refunds_have(swap_id: u64, storage: &dyn Storage) -> bool {
     let key = refunds_key(swap_id);
     storage.get(&key).is_some()
 }
+
+fn reverse_swaps_key(rid: u64) -> Vec<u8> {
+    let mut k = to_length_prefixed(REVERSE_SWAPS_KEY.as_bytes());
+    k.extend_from_slice(&rid.to_be_bytes());
+    k
+}
+
+pub fn reverse_swaps_add(rid: u64, storage: &mut dyn Storage) {
+    let key = reverse_swaps_key(rid);
+    storage.set(&key, &[1]);
+}
+
+pub fn reverse_swaps_have(rid: u64, storage: &dyn Storage) -> bool {
+    let key = reverse_swaps_key(rid);
+    storage.get(&key).is_some()
+}
@@ -1,34 +1,46 @@
-use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, mock_env, mock_info};
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, message_info, mock_env};
 use cosmwasm_std::{
-    Addr, BankMsg, CosmosMsg, Deps, DepsMut, Empty, MessageInfo, OwnedDeps, Response, StdError,
-    StdResult, coins,
+    Addr, BankMsg, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, OwnedDeps, Response, attr,
+    coins, from_json,
 };
 use std::marker::PhantomData;
 
 use crate::access_control::{
-    ADMIN_ROLE, APPROVER_ROLE, AccessRole, MONITOR_ROLE, RELAYER_ROLE, ac_have_role,
+    ADMIN_ROLE, APPROVER_ROLE, AccessRole, MONITOR_ROLE, PendingAdmin, RELAYER_ROLE, ROLE_MEMBERS,
+    ac_admin_count, ac_have_role, ac_rebuild_role_index,
 };
 use crate::contract::{
     amount_from_funds, execute, instantiate, query, verify_not_paused_public_api,
     verify_not_paused_relayer_api,
 };
 use crate::error::{
-    ERR_ACCESS_CONTROL_ALREADY_HAS_ROLE, ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE,
-    ERR_ACCESS_CONTROL_ONLY_ADMIN, ERR_ACCESS_CONTROL_ONLY_RELAYER, ERR_ALREADY_REFUNDED,
-    ERR_CAP_EXCEEDED, ERR_CONTRACT_PAUSED, ERR_EON, ERR_INVALID_SWAP_ID, ERR_RA_ALLOWANCE_EXCEEDED,
-    ERR_SUPPLY_EXCEEDED, ERR_SWAP_LIMITS_INCONSISTENT, ERR_SWAP_LIMITS_VIOLATED,
-    ERR_UNRECOGNIZED_DENOM,
+    ContractError, ERR_ACCESS_CONTROL_ADMIN_PROPOSAL_EXPIRED, ERR_ACCESS_CONTROL_ALREADY_HAS_ROLE,
+    ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE, ERR_ACCESS_CONTROL_LAST_ADMIN,
+    ERR_ACCESS_CONTROL_NOT_PENDING_ADMIN, ERR_ACCESS_CONTROL_ONLY_ADMIN,
+    ERR_ACCESS_CONTROL_ONLY_RELAYER, ERR_ALREADY_REFUNDED, ERR_ALREADY_REVERSE_SWAPPED,
+    ERR_CAP_EXCEEDED, ERR_CONTRACT_PAUSED, ERR_EON, ERR_INVALID_DESTINATION, ERR_INVALID_SWAP_ID,
+    ERR_RA_ALLOWANCE_EXCEEDED, ERR_REFUND_AMOUNT_EXCEEDED, ERR_REFUND_AMOUNT_NOT_FULL,
+    ERR_REVERSE_SWAP_ID_GAP_EXCEEDED, ERR_REVERSE_SWAP_ID_OUT_OF_ORDER, ERR_SUPPLY_EXCEEDED,
+    ERR_SWAP_LIMITS_INCONSISTENT, ERR_SWAP_LIMITS_VIOLATED, ERR_UNRECOGNIZED_DENOM,
 };
 use crate::state::CONFIG;
 
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, Uint128};
-use crate::state::State;
+use crate::msg::{
+    ExecuteMsg, InstantiateMsg, ListRoleMembersResponse, ListSwapsResponse, PendingAdminResponse,
+    QueryMsg, RefundResponse, ReverseSwapIdSealResponse, RolesOfResponse, SwapResponse, Uint128,
+};
+use crate::state::{
+    ASSETS, Asset, AssetKind, RefundRecord, ReverseSwapIdGap, ReverseSwapIdOrdering, State,
+    SwapRecord,
+};
 
 pub const DEFAULT_OWNER: &str = "fetch1pfw2l8sl32es5p94d07yuaw7uffgqtd68wvgsx";
 pub const ACC1: &str = "fetch1dwanqc93pvyku8j7qv22try0m6k4wuhkplc9p3";
 
 pub const ACC2: &str = "fetch1e4vadfa4ktp302jg22gyc3maryjgqj7rrq0h25";
 
+pub const ETH_ACC1: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
 pub const DEFAULT_DENUM: &str = "atestfet";
 pub const DEFAULT_CAP: u128 = 100000u128;
 pub const DEFAULT_RA_ALLOWANCE: u128 = 10000u128;
@@ -38,6 +50,9 @@ pub const DEFAULT_SWAP_UPPER_LIMIT: u128 = 1000u128;
 pub const DEFAULT_SWAP_LOWER_LIMIT: u128 = 110u128;
 pub const DEFAULT_SWAP_FEE: u128 = 100u128;
 
+pub const RELAYER: &str = "relayer";
+pub const MONITOR: &str = "monitor";
+
 pub const HAS_ROLE_TRUE: &[u8] = b"{\"has_role\":true}";
 pub const HAS_ROLE_FALSE: &[u8] = b"{\"has_role\":false}";
 
@@ -72,14 +87,47 @@ macro_rules! str_from_binary {
 
 macro_rules! expect_error {
     ($val1:expr, $val2:expr) => {
-        match $val1 {
+        match &$val1 {
             Ok(_) => panic!("expected error: {:?}", $val2),
-            Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, $val2),
-            Err(e) => panic!("unexpected error: {:?}", e),
+            Err(e) => assert!(
+                e.code().is_some() && e.to_string().starts_with($val2),
+                "unexpected error: {:?}, expected: {:?}",
+                e,
+                $val2
+            ),
         }
     };
 }
 
+// block `block_height`, its time moving on 5s per block from the mock one
+fn env_at(block_height: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = env
+        .block
+        .time
+        .plus_seconds((block_height - env.block.height) * 5);
+    env.block.height = block_height;
+    env
+}
+
+fn exec_at(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    caller: &str,
+    block_height: u64,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let info = message_info(&addr!(caller), &[]);
+    execute(deps.as_mut(), env_at(block_height), info, msg)
+}
+
+// default instance with a relayer, a monitor and a deposit to pay out from
+fn setup(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+    init::init_default(deps).unwrap();
+    access_control::grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+    access_control::grant_role(deps, MONITOR_ROLE, MONITOR, DEFAULT_OWNER).unwrap();
+    deposit::deposit(deps, 10000u128, DEFAULT_OWNER).unwrap();
+}
+
 mod init {
     use super::*;
 
@@ -88,14 +136,14 @@ mod init {
         msg: InstantiateMsg,
         caller: &str,
         amount: u128,
-    ) -> StdResult<Response> {
-        let info = mock_info(caller, &coins(amount, DEFAULT_DENUM));
+    ) -> Result<Response, ContractError> {
+        let info = message_info(&addr!(caller), &coins(amount, DEFAULT_DENUM));
         return instantiate(deps.as_mut(), mock_env(), info, msg);
     }
 
     pub fn init_default(
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = InstantiateMsg {
             cap: cu128!(DEFAULT_CAP),
             reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE),
@@ -106,6 +154,7 @@ mod init {
             paused_since_block: None,
             denom: Some(DEFAULT_DENUM.to_string()),
             next_swap_id: 0,
+            tokenfactory_dialect: None,
         };
         return mock_init(deps, msg, DEFAULT_OWNER, 0);
     }
@@ -121,27 +170,35 @@ mod init {
 
         // check state
         let expected_state = State {
-            supply: cu128!(0u128),
-            fees_accrued: Uint128::from(0u128),
             next_swap_id: 0,
             sealed_reverse_swap_id: 0,
             relay_eon: 0,
+            denom: DEFAULT_DENUM.to_string(),
+        };
+        let expected_asset = Asset {
+            denom: DEFAULT_DENUM.to_string(),
+            kind: AssetKind::Native,
+            supply: cu128!(0u128),
+            fees_accrued: Uint128::from(0u128),
+            cap: cu128!(DEFAULT_CAP),
             upper_swap_limit: cu128!(DEFAULT_SWAP_UPPER_LIMIT),
             lower_swap_limit: cu128!(DEFAULT_SWAP_LOWER_LIMIT),
-            cap: cu128!(DEFAULT_CAP),
+            swap_fee: cu128!(DEFAULT_SWAP_FEE),
+            fee_policy: None,
             reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE),
             reverse_aggregated_allowance_approver_cap: cu128!(DEFAULT_RA_ALLOWANCE_APPROVER_CAP),
-            swap_fee: cu128!(DEFAULT_SWAP_FEE),
             paused_since_block_public_api: u64::MAX,
             paused_since_block_relayer_api: u64::MAX,
-            denom: DEFAULT_DENUM.to_string(),
-            contract_addr_human: mock_env().contract.address,
         };
 
         let state = CONFIG
             .load(&deps.storage)
             .expect("unexpected reading state error");
         assert_eq!(state, expected_state);
+        let asset = ASSETS
+            .load(&deps.storage, DEFAULT_DENUM)
+            .expect("unexpected reading asset error");
+        assert_eq!(asset, expected_asset);
 
         // check role
         ac_have_role(&deps.storage, &addr!(DEFAULT_OWNER), &AccessRole::Admin)
@@ -162,6 +219,7 @@ mod init {
             paused_since_block: None,
             denom: None,
             next_swap_id: 0,
+            tokenfactory_dialect: None,
         };
         let response = mock_init(&mut deps, msg, DEFAULT_OWNER, 1);
         expect_error!(response, ERR_SWAP_LIMITS_INCONSISTENT);
@@ -181,11 +239,12 @@ mod init {
             paused_since_block: None,
             denom: None,
             next_swap_id: 0,
+            tokenfactory_dialect: None,
         };
         let response = mock_init(&mut deps, msg, DEFAULT_OWNER, 1);
         match response {
             Ok(_) => panic!("expected error"),
-            Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, ERR_SWAP_LIMITS_INCONSISTENT),
+            Err(ContractError::SwapLimitsInconsistent {}) => {}
             Err(e) => panic!("unexpected error: {:?}", e),
         }
     }
@@ -200,12 +259,12 @@ mod access_control {
         role: &str,
         account: &str,
         caller: &str,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::GrantRole {
             role: String::from(role),
             address: addr!(account),
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
@@ -248,12 +307,12 @@ mod access_control {
         role: &str,
         account: &str,
         caller: &str,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::RevokeRole {
             role: String::from(role),
             address: addr!(account),
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
@@ -295,11 +354,11 @@ mod access_control {
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         role: &str,
         account: &str,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::RenounceRole {
             role: String::from(role),
         };
-        let info = mock_info(account, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(account), &coins(0, DEFAULT_DENUM));
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
@@ -517,6 +576,246 @@ mod access_control {
         let response = renounce_role(&mut deps, role, account);
         expect_error!(response, ERR_ACCESS_CONTROL_DOESNT_HAVE_ROLE)
     }
+
+    fn list_role_members(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        role: &str,
+        start_after: Option<&str>,
+        limit: Option<u32>,
+    ) -> Vec<Addr> {
+        let query_msg = QueryMsg::ListRoleMembers {
+            role: String::from(role),
+            start_after: start_after.map(|a| addr!(a)),
+            limit,
+        };
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        from_json::<ListRoleMembersResponse>(&response)
+            .unwrap()
+            .members
+    }
+
+    #[test]
+    fn success_list_role_members() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        assert_eq!(
+            vec![addr!(DEFAULT_OWNER)],
+            list_role_members(&deps, ADMIN_ROLE, None, None)
+        );
+        assert!(list_role_members(&deps, RELAYER_ROLE, None, None).is_empty());
+
+        for account in ["relayer_c", "relayer_a", "relayer_b"] {
+            grant_role(&mut deps, RELAYER_ROLE, account, DEFAULT_OWNER).unwrap();
+        }
+        assert_eq!(
+            vec![addr!("relayer_a"), addr!("relayer_b"), addr!("relayer_c")],
+            list_role_members(&deps, RELAYER_ROLE, None, None)
+        );
+        assert_eq!(
+            vec![addr!("relayer_b")],
+            list_role_members(&deps, RELAYER_ROLE, Some("relayer_a"), Some(1))
+        );
+
+        revoke_role(&mut deps, RELAYER_ROLE, "relayer_b", DEFAULT_OWNER).unwrap();
+        renounce_role(&mut deps, RELAYER_ROLE, "relayer_c").unwrap();
+        assert_eq!(
+            vec![addr!("relayer_a")],
+            list_role_members(&deps, RELAYER_ROLE, None, None)
+        );
+    }
+
+    #[test]
+    fn success_roles_of() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let account = "multi_role";
+        grant_role(&mut deps, MONITOR_ROLE, account, DEFAULT_OWNER).unwrap();
+        grant_role(&mut deps, RELAYER_ROLE, account, DEFAULT_OWNER).unwrap();
+
+        let query_msg = QueryMsg::RolesOf {
+            address: addr!(account),
+        };
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        let roles = from_json::<RolesOfResponse>(&response).unwrap().roles;
+        assert_eq!(vec![RELAYER_ROLE, MONITOR_ROLE], roles);
+    }
+
+    #[test]
+    fn success_rebuild_role_index() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        grant_role(&mut deps, RELAYER_ROLE, "relayer", DEFAULT_OWNER).unwrap();
+
+        // roles granted before the index existed
+        ROLE_MEMBERS.clear(&mut deps.storage);
+        assert!(list_role_members(&deps, ADMIN_ROLE, None, None).is_empty());
+
+        ac_rebuild_role_index(&mut deps.storage).unwrap();
+        assert_eq!(
+            vec![addr!(DEFAULT_OWNER)],
+            list_role_members(&deps, ADMIN_ROLE, None, None)
+        );
+        assert_eq!(
+            vec![addr!("relayer")],
+            list_role_members(&deps, RELAYER_ROLE, None, None)
+        );
+    }
+
+    #[test]
+    fn failure_remove_last_admin() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = revoke_role(&mut deps, ADMIN_ROLE, DEFAULT_OWNER, DEFAULT_OWNER);
+        expect_error!(response, ERR_ACCESS_CONTROL_LAST_ADMIN);
+        let response = renounce_role(&mut deps, ADMIN_ROLE, DEFAULT_OWNER);
+        expect_error!(response, ERR_ACCESS_CONTROL_LAST_ADMIN);
+        assert!(ac_have_role(&deps.storage, &addr!(DEFAULT_OWNER), &AccessRole::Admin).unwrap());
+
+        // fine as long as another admin remains
+        grant_role(&mut deps, ADMIN_ROLE, ACC1, DEFAULT_OWNER).unwrap();
+        renounce_role(&mut deps, ADMIN_ROLE, DEFAULT_OWNER).unwrap();
+        let response = revoke_role(&mut deps, ADMIN_ROLE, ACC1, ACC1);
+        expect_error!(response, ERR_ACCESS_CONTROL_LAST_ADMIN);
+        assert_eq!(1, ac_admin_count(&deps.storage).unwrap());
+    }
+
+    fn propose_admin(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        account: &str,
+        expires_at_block: Option<u64>,
+        caller: &str,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::ProposeAdmin {
+            address: addr!(account),
+            expires_at_block,
+        };
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
+        execute(deps.as_mut(), mock_env(), info, msg)
+    }
+
+    fn accept_admin(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        caller: &str,
+        block_height: u64,
+    ) -> Result<Response, ContractError> {
+        let mut env = mock_env();
+        env.block.height = block_height;
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
+        execute(deps.as_mut(), env, info, ExecuteMsg::AcceptAdmin {})
+    }
+
+    fn pending_admin(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> Option<PendingAdmin> {
+        let response = query(deps.as_ref(), mock_env(), QueryMsg::PendingAdmin {}).unwrap();
+        from_json::<PendingAdminResponse>(&response)
+            .unwrap()
+            .pending_admin
+    }
+
+    #[test]
+    fn success_admin_handover() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let expires_at_block = mock_env().block.height + 10;
+        propose_admin(&mut deps, ACC1, Some(expires_at_block), DEFAULT_OWNER).unwrap();
+        assert_eq!(
+            Some(PendingAdmin {
+                address: addr!(ACC1),
+                proposer: addr!(DEFAULT_OWNER),
+                expires_at_block: Some(expires_at_block),
+            }),
+            pending_admin(&deps)
+        );
+
+        let response = accept_admin(&mut deps, ACC1, expires_at_block).unwrap();
+        assert!(
+            response.attributes[0].key == "action"
+                && response.attributes[0].value == "accept_admin"
+        );
+        assert!(ac_have_role(&deps.storage, &addr!(ACC1), &AccessRole::Admin).unwrap());
+        assert!(!ac_have_role(&deps.storage, &addr!(DEFAULT_OWNER), &AccessRole::Admin).unwrap());
+        assert_eq!(1, ac_admin_count(&deps.storage).unwrap());
+        assert_eq!(None, pending_admin(&deps));
+    }
+
+    #[test]
+    fn failure_accept_admin_not_pending() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = accept_admin(&mut deps, ACC1, mock_env().block.height);
+        expect_error!(response, ERR_ACCESS_CONTROL_NOT_PENDING_ADMIN);
+
+        propose_admin(&mut deps, ACC1, None, DEFAULT_OWNER).unwrap();
+        let response = accept_admin(&mut deps, ACC2, mock_env().block.height);
+        expect_error!(response, ERR_ACCESS_CONTROL_NOT_PENDING_ADMIN);
+    }
+
+    #[test]
+    fn failure_accept_admin_expired() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let expires_at_block = mock_env().block.height + 10;
+        propose_admin(&mut deps, ACC1, Some(expires_at_block), DEFAULT_OWNER).unwrap();
+        let response = accept_admin(&mut deps, ACC1, expires_at_block + 1);
+        expect_error!(response, ERR_ACCESS_CONTROL_ADMIN_PROPOSAL_EXPIRED);
+        assert!(ac_have_role(&deps.storage, &addr!(DEFAULT_OWNER), &AccessRole::Admin).unwrap());
+    }
+
+    #[test]
+    fn failure_propose_admin() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = propose_admin(&mut deps, ACC1, None, ACC2);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        let response = propose_admin(&mut deps, DEFAULT_OWNER, None, DEFAULT_OWNER);
+        expect_error!(response, ERR_ACCESS_CONTROL_ALREADY_HAS_ROLE);
+        let response = propose_admin(
+            &mut deps,
+            ACC1,
+            Some(mock_env().block.height - 1),
+            DEFAULT_OWNER,
+        );
+        expect_error!(response, ERR_ACCESS_CONTROL_ADMIN_PROPOSAL_EXPIRED);
+    }
+
+    #[test]
+    fn success_cancel_admin_proposal() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        propose_admin(&mut deps, ACC1, None, DEFAULT_OWNER).unwrap();
+        let info = message_info(&addr!(DEFAULT_OWNER), &coins(0, DEFAULT_DENUM));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::CancelAdminProposal {},
+        )
+        .unwrap();
+        assert_eq!(None, pending_admin(&deps));
+
+        let response = accept_admin(&mut deps, ACC1, mock_env().block.height);
+        expect_error!(response, ERR_ACCESS_CONTROL_NOT_PENDING_ADMIN);
+    }
+
+    #[test]
+    fn failure_list_role_members_unknown_role() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let query_msg = QueryMsg::ListRoleMembers {
+            role: String::from("UNKNOWN_ROLE"),
+            start_after: None,
+            limit: None,
+        };
+        assert!(query(deps.as_ref(), mock_env(), query_msg).is_err());
+    }
 }
 
 mod pause {
@@ -528,8 +827,11 @@ mod pause {
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         info: MessageInfo,
         since_block: u64,
-    ) -> StdResult<Response> {
-        let msg = ExecuteMsg::PausePublicApi { since_block };
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::PausePublicApi {
+            since_block,
+            asset: None,
+        };
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
@@ -537,48 +839,46 @@ mod pause {
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         info: MessageInfo,
         since_block: u64,
-    ) -> StdResult<Response> {
-        let msg = ExecuteMsg::PauseRelayerApi { since_block };
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::PauseRelayerApi {
+            since_block,
+            asset: None,
+        };
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
     pub fn pause_public_api(
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         info: MessageInfo,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         _pause_public_api(deps, info, 0)
     }
 
     pub fn pause_relayer_api(
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         info: MessageInfo,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         _pause_relayer_api(deps, info, 0)
     }
 
     pub fn unpause_public_api(
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         info: MessageInfo,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         _pause_public_api(deps, info, u64::MAX)
     }
 
     pub fn unpause_relayer_api(
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         info: MessageInfo,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         _pause_relayer_api(deps, info, u64::MAX)
     }
 
     fn assert_pause_both(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, caller: &str) {
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
-        assert!(
-            verify_not_paused_public_api(&mock_env(), &CONFIG.load(&deps.storage).unwrap()).is_ok()
-        );
-        assert!(
-            verify_not_paused_relayer_api(&mock_env(), &CONFIG.load(&deps.storage).unwrap())
-                .is_ok()
-        );
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
+        assert!(verify_not_paused_public_api(&mock_env(), &deps.storage).is_ok());
+        assert!(verify_not_paused_relayer_api(&mock_env(), &deps.storage).is_ok());
 
         let mut response = pause_public_api(deps, info.clone()).unwrap();
         // handle response
@@ -593,17 +893,8 @@ mod pause {
                 && response.attributes[1].value == mock_env().block.height.to_string()
         );
         // state
-        assert!(
-            verify_not_paused_public_api(&mock_env(), &CONFIG.load(deps.as_mut().storage).unwrap())
-                .is_err()
-        );
-        assert!(
-            verify_not_paused_relayer_api(
-                &mock_env(),
-                &CONFIG.load(deps.as_mut().storage).unwrap()
-            )
-            .is_ok()
-        );
+        assert!(verify_not_paused_public_api(&mock_env(), deps.as_mut().storage).is_err());
+        assert!(verify_not_paused_relayer_api(&mock_env(), deps.as_mut().storage).is_ok());
 
         response = pause_relayer_api(deps, info.clone()).unwrap();
         // handle response
@@ -618,13 +909,7 @@ mod pause {
                 && response.attributes[1].value == mock_env().block.height.to_string()
         );
         // state
-        assert!(
-            verify_not_paused_relayer_api(
-                &mock_env(),
-                &CONFIG.load(deps.as_mut().storage).unwrap()
-            )
-            .is_err()
-        );
+        assert!(verify_not_paused_relayer_api(&mock_env(), deps.as_mut().storage).is_err());
     }
 
     fn assert_query_paused(
@@ -632,7 +917,7 @@ mod pause {
         public_api_paused: bool,
         relayer_api_paused: bool,
     ) {
-        let query_msg = QueryMsg::PausedPublicApiSince {};
+        let query_msg = QueryMsg::PausedPublicApiSince { asset: None };
         let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
         if public_api_paused {
             assert_eq!(
@@ -646,7 +931,7 @@ mod pause {
             );
         }
 
-        let query_msg = QueryMsg::PausedRelayerApiSince {};
+        let query_msg = QueryMsg::PausedRelayerApiSince { asset: None };
         let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
         if relayer_api_paused {
             assert_eq!(
@@ -689,7 +974,7 @@ mod pause {
         init_default(&mut deps).unwrap();
 
         let caller = DEFAULT_OWNER;
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         pause_public_api(&mut deps, info.clone()).unwrap();
         pause_relayer_api(&mut deps, info.clone()).unwrap();
 
@@ -706,13 +991,8 @@ mod pause {
                 && response.attributes[1].value == u64::MAX.to_string()
         );
         // state
-        assert!(
-            verify_not_paused_public_api(&mock_env(), &CONFIG.load(&deps.storage).unwrap()).is_ok()
-        );
-        assert!(
-            verify_not_paused_relayer_api(&mock_env(), &CONFIG.load(&deps.storage).unwrap())
-                .is_err()
-        );
+        assert!(verify_not_paused_public_api(&mock_env(), &deps.storage).is_ok());
+        assert!(verify_not_paused_relayer_api(&mock_env(), &deps.storage).is_err());
         assert_query_paused(&mut deps, false, true);
 
         response = unpause_relayer_api(&mut deps, info.clone()).unwrap();
@@ -729,8 +1009,8 @@ mod pause {
         );
         // state
         let storage = deps.as_mut().storage;
-        assert!(verify_not_paused_public_api(&mock_env(), &CONFIG.load(storage).unwrap()).is_ok());
-        assert!(verify_not_paused_relayer_api(&mock_env(), &CONFIG.load(storage).unwrap()).is_ok());
+        assert!(verify_not_paused_public_api(&mock_env(), storage).is_ok());
+        assert!(verify_not_paused_relayer_api(&mock_env(), storage).is_ok());
         assert_query_paused(&mut deps, false, false);
     }
 
@@ -740,7 +1020,7 @@ mod pause {
         init_default(&mut deps).unwrap();
 
         let caller = "user";
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
 
         let mut response = pause_public_api(&mut deps, info.clone());
         expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
@@ -757,7 +1037,7 @@ mod pause {
         let monitor = "new_monitor";
         grant_role(&mut deps, MONITOR_ROLE, monitor, DEFAULT_OWNER).unwrap();
 
-        let info = mock_info(monitor, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(monitor), &coins(0, DEFAULT_DENUM));
         pause_public_api(&mut deps, info.clone()).unwrap();
         pause_relayer_api(&mut deps, info.clone()).unwrap();
 
@@ -766,14 +1046,8 @@ mod pause {
         response = unpause_relayer_api(&mut deps, info.clone());
         expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
 
-        assert!(
-            verify_not_paused_public_api(&mock_env(), &CONFIG.load(&deps.storage).unwrap())
-                .is_err()
-        );
-        assert!(
-            verify_not_paused_relayer_api(&mock_env(), &CONFIG.load(&deps.storage).unwrap())
-                .is_err()
-        );
+        assert!(verify_not_paused_public_api(&mock_env(), &deps.storage).is_err());
+        assert!(verify_not_paused_relayer_api(&mock_env(), &deps.storage).is_err());
     }
 }
 
@@ -785,9 +1059,9 @@ mod deposit {
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         amount: u128,
         caller: &str,
-    ) -> StdResult<Response> {
-        let msg = ExecuteMsg::Deposit {};
-        let info = mock_info(caller, &coins(amount, DEFAULT_DENUM));
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Deposit { asset: None };
+        let info = message_info(&addr!(caller), &coins(amount, DEFAULT_DENUM));
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
@@ -801,7 +1075,8 @@ mod deposit {
 
         // check handle response
         assert_eq!(0, response.messages.len());
-        assert_eq!(3, response.attributes.len());
+        assert_eq!(4, response.attributes.len());
+        assert_eq!(attr("denom", DEFAULT_DENUM), response.attributes[3]);
         assert!(
             response.attributes[0].key == "action" && response.attributes[0].value == "deposit"
         );
@@ -814,10 +1089,10 @@ mod deposit {
         );
 
         // check contract state
-        let state = CONFIG
-            .load(&deps.storage)
+        let asset = ASSETS
+            .load(&deps.storage, DEFAULT_DENUM)
             .expect("unexpected reading state error");
-        assert_eq!(cu128!(amount), state.supply);
+        assert_eq!(cu128!(amount), asset.supply);
     }
 
     #[test]
@@ -850,9 +1125,9 @@ mod deposit {
         init_default(&mut deps).unwrap();
 
         let amount = 1000u128;
-        let msg = ExecuteMsg::Deposit {};
+        let msg = ExecuteMsg::Deposit { asset: None };
         let denom = "WRONG";
-        let info = mock_info(DEFAULT_OWNER, &coins(amount, denom));
+        let info = message_info(&addr!(DEFAULT_OWNER), &coins(amount, denom));
         let response = execute(deps.as_mut(), mock_env(), info, msg);
         expect_error!(response, ERR_UNRECOGNIZED_DENOM);
     }
@@ -867,9 +1142,9 @@ mod new_relay_eon {
     pub fn new_relay_eon(
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         caller: &str,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::NewRelayEon {};
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
@@ -921,7 +1196,7 @@ mod new_relay_eon {
 
         let relayer = "new_relayer";
         grant_role(&mut deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
-        let info = mock_info(DEFAULT_OWNER, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(DEFAULT_OWNER), &coins(0, DEFAULT_DENUM));
         pause_relayer_api(&mut deps, info).unwrap();
 
         let response = new_relay_eon(&mut deps, relayer);
@@ -937,12 +1212,18 @@ mod swap {
     use init::init_default;
     use pause::pause_public_api;
 
-    pub fn swap(deps: DepsMut, from: &str, destination: &str, amount: u128) -> StdResult<Response> {
+    pub fn swap(
+        deps: DepsMut,
+        from: &str,
+        destination: &str,
+        amount: u128,
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::Swap {
             destination: destination.to_string(),
+            asset: None,
         };
 
-        let info = mock_info(from, &coins(amount, DEFAULT_DENUM));
+        let info = message_info(&addr!(from), &coins(amount, DEFAULT_DENUM));
         execute(deps, mock_env(), info, msg)
     }
 
@@ -952,13 +1233,14 @@ mod swap {
         init_default(&mut deps).unwrap();
 
         let fet_account = "user_account";
-        let eth_account = "some_eth_account";
+        let eth_account = ETH_ACC1;
         let amount = 110u128;
         let response = swap(deps.as_mut(), fet_account, eth_account, amount).unwrap();
 
         // check handle response
         assert_eq!(0, response.messages.len());
-        assert_eq!(4, response.attributes.len());
+        assert_eq!(5, response.attributes.len());
+        assert_eq!(attr("denom", DEFAULT_DENUM), response.attributes[4]);
         assert!(response.attributes[0].key == "action" && response.attributes[0].value == "swap");
         assert!(
             response.attributes[1].key == "destination"
@@ -972,16 +1254,116 @@ mod swap {
 
         // check contract state
         let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
         assert_eq!(1u64, state.next_swap_id);
-        assert_eq!(cu128!(amount), state.supply);
+        assert_eq!(cu128!(amount), asset.supply);
 
         // query
-        let query_msg = QueryMsg::Supply {};
+        let query_msg = QueryMsg::Supply { asset: None };
         let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
         assert_eq!(
             format!("{{\"amount\":\"{}\"}}", amount),
             str_from_binary!(response)
         );
+
+        // stored swap
+        let query_msg = QueryMsg::Swap { id: 0 };
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        let swap: SwapResponse = from_json(response).unwrap();
+        let expected_swap = SwapRecord {
+            id: 0,
+            denom: DEFAULT_DENUM.to_string(),
+            sender: addr!(fet_account),
+            destination: eth_account.to_string(),
+            amount: cu128!(amount),
+            block_height: mock_env().block.height,
+            time: mock_env().block.time,
+        };
+        assert_eq!(expected_swap, swap);
+    }
+
+    #[test]
+    fn success_list_swaps() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let eth_account = ETH_ACC1;
+        for i in 0..5u128 {
+            let sender = if i % 2 == 0 { ACC1 } else { ACC2 };
+            swap(
+                deps.as_mut(),
+                sender,
+                eth_account,
+                DEFAULT_SWAP_LOWER_LIMIT + i,
+            )
+            .unwrap();
+        }
+
+        let list_swaps = |start_after: Option<u64>, limit: Option<u32>, sender: Option<&str>| {
+            let query_msg = QueryMsg::ListSwaps {
+                start_after,
+                limit,
+                sender: sender.map(|s| addr!(s)),
+            };
+            let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+            let response: ListSwapsResponse = from_json(response).unwrap();
+            response
+                .swaps
+                .iter()
+                .map(|swap| swap.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![0, 1, 2, 3, 4], list_swaps(None, None, None));
+        assert_eq!(vec![2, 3], list_swaps(Some(1), Some(2), None));
+        assert_eq!(vec![0, 2, 4], list_swaps(None, None, Some(ACC1)));
+        assert_eq!(vec![4], list_swaps(Some(2), None, Some(ACC1)));
+        assert_eq!(vec![1, 3], list_swaps(None, None, Some(ACC2)));
+    }
+
+    #[test]
+    fn success_swap_destination_normalized() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let amount = 110u128;
+        for destination in [
+            ETH_ACC1.to_lowercase(),
+            ETH_ACC1.to_uppercase().replace("0X", "0x"),
+        ] {
+            let response = swap(deps.as_mut(), ACC1, &destination, amount).unwrap();
+            assert!(
+                response.attributes[1].key == "destination"
+                    && response.attributes[1].value == ETH_ACC1
+            );
+        }
+    }
+
+    #[test]
+    fn failure_swap_invalid_destination() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let amount = 110u128;
+        let invalid_destinations = [
+            "some_eth_account",
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAe",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAedd",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg",
+            // wrong checksum
+            "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        ];
+        for destination in invalid_destinations {
+            let response = swap(deps.as_mut(), ACC1, destination, amount);
+            expect_error!(response, ERR_INVALID_DESTINATION);
+        }
+
+        // check contract state
+        let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(0u64, state.next_swap_id);
+        assert_eq!(cu128!(0u128), asset.supply);
     }
 
     #[test]
@@ -990,26 +1372,30 @@ mod swap {
         init_default(&mut deps).unwrap();
 
         let fet_account = "user_account";
-        let eth_account = "some_eth_account";
+        let eth_account = ETH_ACC1;
         let mut amount: u128;
         let msg = ExecuteMsg::Swap {
             destination: eth_account.to_string(),
+            asset: None,
         };
 
         amount = DEFAULT_SWAP_LOWER_LIMIT - 10u128;
-        let info = mock_info(fet_account, &coins(amount, DEFAULT_DENUM));
+        let info = message_info(&addr!(fet_account), &coins(amount, DEFAULT_DENUM));
         let response = execute(deps.as_mut(), mock_env(), info, msg.clone());
         expect_error!(response, ERR_SWAP_LIMITS_VIOLATED);
 
         amount = DEFAULT_SWAP_UPPER_LIMIT + 10u128;
-        let info = mock_info(fet_account, &coins(amount, DEFAULT_DENUM));
+        let info = message_info(&addr!(fet_account), &coins(amount, DEFAULT_DENUM));
         let response = execute(deps.as_mut(), mock_env(), info, msg.clone());
         expect_error!(response, ERR_SWAP_LIMITS_VIOLATED);
+        assert_eq!(Some("FET_ERROR_SWAP_LIMITS"), response.unwrap_err().code());
 
         // check contract state
         let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
         assert_eq!(0u64, state.next_swap_id);
-        assert_eq!(cu128!(0u128), state.supply);
+        assert_eq!(cu128!(0u128), asset.supply);
+        assert!(query(deps.as_ref(), mock_env(), QueryMsg::Swap { id: 0 }).is_err());
     }
 
     #[test]
@@ -1018,24 +1404,25 @@ mod swap {
         init_default(&mut deps).unwrap();
 
         let fet_account = "user_account";
-        let eth_account = "some_eth_account";
+        let eth_account = ETH_ACC1;
         let amount = DEFAULT_SWAP_UPPER_LIMIT;
         let msg = ExecuteMsg::Swap {
             destination: eth_account.to_string(),
+            asset: None,
         };
 
         for _ in 0..(DEFAULT_CAP / DEFAULT_SWAP_UPPER_LIMIT) {
-            let info = mock_info(fet_account, &coins(amount, DEFAULT_DENUM));
+            let info = message_info(&addr!(fet_account), &coins(amount, DEFAULT_DENUM));
             execute(deps.as_mut(), mock_env(), info, msg.clone()).unwrap();
         }
 
-        let info = mock_info(fet_account, &coins(amount, DEFAULT_DENUM));
+        let info = message_info(&addr!(fet_account), &coins(amount, DEFAULT_DENUM));
         let response = execute(deps.as_mut(), mock_env(), info, msg.clone());
         expect_error!(response, ERR_CAP_EXCEEDED);
 
         // check contract state
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert!(state.supply <= cu128!(DEFAULT_CAP));
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert!(asset.supply <= cu128!(DEFAULT_CAP));
     }
 
     #[test]
@@ -1043,24 +1430,26 @@ mod swap {
         let mut deps = mock_deps();
         init_default(&mut deps).unwrap();
 
-        let info = mock_info(DEFAULT_OWNER, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(DEFAULT_OWNER), &coins(0, DEFAULT_DENUM));
         pause_public_api(&mut deps, info).unwrap();
 
         let fet_account = ACC1;
-        let eth_account = "some_eth_account";
+        let eth_account = ETH_ACC1;
         let amount = DEFAULT_SWAP_UPPER_LIMIT;
         let msg = ExecuteMsg::Swap {
             destination: eth_account.to_string(),
+            asset: None,
         };
 
-        let info = mock_info(fet_account, &coins(amount, DEFAULT_DENUM));
+        let info = message_info(&addr!(fet_account), &coins(amount, DEFAULT_DENUM));
         let response = execute(deps.as_mut(), mock_env(), info, msg.clone());
         expect_error!(response, ERR_CONTRACT_PAUSED);
 
         // check contract state
         let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
         assert_eq!(0u64, state.next_swap_id);
-        assert_eq!(cu128!(0u128), state.supply);
+        assert_eq!(cu128!(0u128), asset.supply);
     }
 }
 
@@ -1081,7 +1470,7 @@ mod reverse_swap {
         hash: &str,
         amount: u128,
         eon: u64,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::ReverseSwap {
             rid: rid,
             to: addr!(to),
@@ -1089,20 +1478,21 @@ mod reverse_swap {
             origin_tx_hash: hash.to_string(),
             amount: cu128!(amount),
             relay_eon: eon,
+            asset: None,
         };
 
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps, mock_env(), info.clone(), msg)
     }
 
     fn assert_state_unchanged(deps: Deps, deposited: u128) {
-        let state = CONFIG.load(deps.storage).unwrap();
-        assert_eq!(cu128!(deposited), state.supply);
+        let asset = ASSETS.load(deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(deposited), asset.supply);
         assert_eq!(
             cu128!(DEFAULT_RA_ALLOWANCE),
-            state.reverse_aggregated_allowance
+            asset.reverse_aggregated_allowance
         );
-        assert_eq!(cu128!(0u128), state.fees_accrued);
+        assert_eq!(cu128!(0u128), asset.fees_accrued);
     }
 
     #[test]
@@ -1116,7 +1506,7 @@ mod reverse_swap {
         deposit(&mut deps, deposited, DEFAULT_OWNER).unwrap();
 
         let fet_account = ACC1;
-        let eth_account = "some_eth_account";
+        let eth_account = ETH_ACC1;
         let amount = 110u128;
         let rid = 0u64;
         let eon = 0u64;
@@ -1135,7 +1525,8 @@ mod reverse_swap {
 
         // check handle response
         assert_eq!(1, response.messages.len());
-        assert_eq!(7, response.attributes.len());
+        assert_eq!(8, response.attributes.len());
+        assert_eq!(attr("denom", DEFAULT_DENUM), response.attributes[7]);
         match &response.messages[0].msg {
             CosmosMsg::Bank(bank) => match bank {
                 BankMsg::Send {
@@ -1177,13 +1568,13 @@ mod reverse_swap {
         );
 
         // check contract state
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(deposited - amount), state.supply);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(deposited - amount), asset.supply);
         assert_eq!(
             cu128!(DEFAULT_RA_ALLOWANCE - amount),
-            state.reverse_aggregated_allowance
+            asset.reverse_aggregated_allowance
         );
-        assert_eq!(cu128!(DEFAULT_SWAP_FEE), state.fees_accrued);
+        assert_eq!(cu128!(DEFAULT_SWAP_FEE), asset.fees_accrued);
     }
 
     #[test]
@@ -1245,7 +1636,7 @@ mod reverse_swap {
         let deposited = 1000u128;
         grant_role(&mut deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
         deposit(&mut deps, deposited, DEFAULT_OWNER).unwrap();
-        let info = mock_info(DEFAULT_OWNER, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(DEFAULT_OWNER), &coins(0, DEFAULT_DENUM));
         pause_relayer_api(&mut deps, info).unwrap();
 
         let response = reverse_swap(
@@ -1284,6 +1675,27 @@ mod reverse_swap {
 
         expect_error!(response, ERR_SUPPLY_EXCEEDED);
         assert_state_unchanged(deps.as_ref(), 0u128);
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            relayer,
+            0u64,
+            ACC1,
+            "eth_account",
+            "HHHHHHHHHAAAAASSSSSSSH",
+            110u128,
+            0u64,
+        );
+        match response {
+            Err(e @ ContractError::SupplyExceeded { .. }) => {
+                assert_eq!(Some("FET_ERR_SUPPLY_EXCEEDED"), e.code());
+                assert_eq!(
+                    format!("{} (requested 110, available 0)", ERR_SUPPLY_EXCEEDED),
+                    e.to_string()
+                );
+            }
+            _ => panic!("unexpected response: {:?}", response),
+        }
     }
 
     #[test]
@@ -1296,11 +1708,12 @@ mod reverse_swap {
         grant_role(&mut deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
         deposit(&mut deps, deposited, DEFAULT_OWNER).unwrap();
 
+        let mut rid = 0u64;
         for _ in 0..(DEFAULT_RA_ALLOWANCE / DEFAULT_SWAP_UPPER_LIMIT) {
             reverse_swap(
                 deps.as_mut(),
                 relayer,
-                0u64,
+                rid,
                 ACC1,
                 "eth_account",
                 "HHHHHHHHHAAAAASSSSSSSH",
@@ -1308,12 +1721,13 @@ mod reverse_swap {
                 0u64,
             )
             .unwrap();
+            rid += 1;
         }
 
         let response = reverse_swap(
             deps.as_mut(),
             relayer,
-            0u64,
+            rid,
             ACC1,
             "eth_account",
             "HHHHHHHHHAAAAASSSSSSSH",
@@ -1322,33 +1736,255 @@ mod reverse_swap {
         );
         expect_error!(response, ERR_RA_ALLOWANCE_EXCEEDED);
     }
-}
 
-mod refund {
-    use super::*;
-    use access_control::grant_role;
-    use deposit::deposit;
-    use init::init_default;
-    use new_relay_eon::new_relay_eon;
-    use pause::pause_relayer_api;
-    use swap::swap;
+    #[test]
+    fn failure_reverse_swap_already_processed() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
 
-    pub fn refund(
-        deps: DepsMut,
-        caller: &str,
-        id: u64,
-        to: &str,
-        amount: u128,
-        eon: u64,
-    ) -> StdResult<Response> {
+        let relayer = "relayer";
+        let deposited = 1000u128;
+        let amount = 110u128;
+        grant_role(&mut deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
+        deposit(&mut deps, deposited, DEFAULT_OWNER).unwrap();
+
+        let rid = 7u64;
+        let query_msg = QueryMsg::ReverseSwapProcessed { rid };
+        let response = query(deps.as_ref(), mock_env(), query_msg.clone()).unwrap();
+        assert_eq!("{\"processed\":false}", str_from_binary!(response));
+
+        reverse_swap(
+            deps.as_mut(),
+            relayer,
+            rid,
+            ACC1,
+            "eth_account",
+            "HHHHHHHHHAAAAASSSSSSSH",
+            amount,
+            0u64,
+        )
+        .unwrap();
+
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        assert_eq!("{\"processed\":true}", str_from_binary!(response));
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            relayer,
+            rid,
+            ACC1,
+            "eth_account",
+            "HHHHHHHHHAAAAASSSSSSSH",
+            amount,
+            0u64,
+        );
+        expect_error!(response, ERR_ALREADY_REVERSE_SWAPPED);
+
+        // check contract state
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(deposited - amount), asset.supply);
+        assert_eq!(
+            cu128!(DEFAULT_RA_ALLOWANCE - amount),
+            asset.reverse_aggregated_allowance
+        );
+    }
+}
+
+mod reverse_swap_id_ordering {
+    use super::*;
+    use access_control::grant_role;
+    use deposit::deposit;
+    use init::init_default;
+    use new_relay_eon::new_relay_eon;
+    use reverse_swap::reverse_swap;
+
+    const RELAYER: &str = "relayer";
+
+    fn set_reverse_swap_id_ordering(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        caller: &str,
+        ordering: ReverseSwapIdOrdering,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::SetReverseSwapIdOrdering { ordering };
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
+        execute(deps.as_mut(), mock_env(), info, msg)
+    }
+
+    fn setup(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        ordering: ReverseSwapIdOrdering,
+    ) {
+        init_default(deps).unwrap();
+        grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+        deposit(deps, 10000u128, DEFAULT_OWNER).unwrap();
+        set_reverse_swap_id_ordering(deps, DEFAULT_OWNER, ordering).unwrap();
+    }
+
+    fn do_reverse_swap(deps: DepsMut, rid: u64) -> Result<Response, ContractError> {
+        reverse_swap(
+            deps,
+            RELAYER,
+            rid,
+            ACC1,
+            "eth_account",
+            "HHHHHHHHHAAAAASSSSSSSH",
+            110u128,
+            0u64,
+        )
+    }
+
+    fn query_seal(deps: Deps) -> ReverseSwapIdSealResponse {
+        let query_msg = QueryMsg::ReverseSwapIdSeal {
+            start_after: None,
+            limit: None,
+        };
+        from_json(query(deps, mock_env(), query_msg).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn success_unordered_advances_sealed_id() {
+        let mut deps = mock_deps();
+        setup(&mut deps, ReverseSwapIdOrdering::Unordered);
+
+        do_reverse_swap(deps.as_mut(), 5u64).unwrap();
+        do_reverse_swap(deps.as_mut(), 2u64).unwrap();
+
+        let seal = query_seal(deps.as_ref());
+        assert_eq!(6u64, seal.sealed_reverse_swap_id);
+        assert_eq!(ReverseSwapIdOrdering::Unordered, seal.ordering);
+        assert!(seal.gaps.is_empty());
+    }
+
+    #[test]
+    fn success_strictly_increasing_records_gaps() {
+        let mut deps = mock_deps();
+        setup(&mut deps, ReverseSwapIdOrdering::StrictlyIncreasing);
+
+        do_reverse_swap(deps.as_mut(), 0u64).unwrap();
+        do_reverse_swap(deps.as_mut(), 1u64).unwrap();
+        do_reverse_swap(deps.as_mut(), 4u64).unwrap();
+
+        let seal = query_seal(deps.as_ref());
+        assert_eq!(5u64, seal.sealed_reverse_swap_id);
+        assert_eq!(
+            vec![ReverseSwapIdGap {
+                start: 2,
+                end: 4,
+                relay_eon: 0,
+            }],
+            seal.gaps
+        );
+
+        let state = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(5u64, state.sealed_reverse_swap_id);
+    }
+
+    #[test]
+    fn failure_strictly_increasing_out_of_order() {
+        let mut deps = mock_deps();
+        setup(&mut deps, ReverseSwapIdOrdering::StrictlyIncreasing);
+
+        do_reverse_swap(deps.as_mut(), 3u64).unwrap();
+        let response = do_reverse_swap(deps.as_mut(), 2u64);
+        expect_error!(response, ERR_REVERSE_SWAP_ID_OUT_OF_ORDER);
+
+        let response = do_reverse_swap(deps.as_mut(), 3u64);
+        expect_error!(response, ERR_ALREADY_REVERSE_SWAPPED);
+        assert_eq!(4u64, query_seal(deps.as_ref()).sealed_reverse_swap_id);
+    }
+
+    #[test]
+    fn success_ordering_spans_relay_eons() {
+        let mut deps = mock_deps();
+        setup(&mut deps, ReverseSwapIdOrdering::StrictlyIncreasing);
+
+        do_reverse_swap(deps.as_mut(), 5u64).unwrap();
+        new_relay_eon(&mut deps, RELAYER).unwrap();
+
+        // a new eon doesn't reopen the rids sealed in the previous one
+        let hash = "HHHHHHHHHAAAAASSSSSSSH";
+        let response = reverse_swap(deps.as_mut(), RELAYER, 3, ACC1, "eth", hash, 110u128, 1);
+        expect_error!(response, ERR_REVERSE_SWAP_ID_OUT_OF_ORDER);
+        reverse_swap(deps.as_mut(), RELAYER, 8, ACC1, "eth", hash, 110u128, 1).unwrap();
+
+        let seal = query_seal(deps.as_ref());
+        assert_eq!(9u64, seal.sealed_reverse_swap_id);
+        assert_eq!(
+            vec![
+                ReverseSwapIdGap {
+                    start: 0,
+                    end: 5,
+                    relay_eon: 0,
+                },
+                ReverseSwapIdGap {
+                    start: 6,
+                    end: 8,
+                    relay_eon: 1,
+                },
+            ],
+            seal.gaps
+        );
+    }
+
+    #[test]
+    fn failure_contiguous_gap_exceeded() {
+        let mut deps = mock_deps();
+        setup(&mut deps, ReverseSwapIdOrdering::Contiguous { max_gap: 1 });
+
+        do_reverse_swap(deps.as_mut(), 0u64).unwrap();
+        do_reverse_swap(deps.as_mut(), 2u64).unwrap();
+        let response = do_reverse_swap(deps.as_mut(), 5u64);
+        expect_error!(response, ERR_REVERSE_SWAP_ID_GAP_EXCEEDED);
+
+        let seal = query_seal(deps.as_ref());
+        assert_eq!(3u64, seal.sealed_reverse_swap_id);
+        assert_eq!(1, seal.gaps.len());
+    }
+
+    #[test]
+    fn failure_set_reverse_swap_id_ordering_not_admin() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = set_reverse_swap_id_ordering(
+            &mut deps,
+            "not_admin",
+            ReverseSwapIdOrdering::StrictlyIncreasing,
+        );
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        assert_eq!(
+            ReverseSwapIdOrdering::Unordered,
+            query_seal(deps.as_ref()).ordering
+        );
+    }
+}
+
+mod refund {
+    use super::*;
+    use access_control::grant_role;
+    use deposit::deposit;
+    use init::init_default;
+    use new_relay_eon::new_relay_eon;
+    use pause::pause_relayer_api;
+    use swap::swap;
+
+    pub fn refund(
+        deps: DepsMut,
+        caller: &str,
+        id: u64,
+        to: &str,
+        amount: u128,
+        eon: u64,
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::Refund {
             id: id,
             to: addr!(to),
             amount: cu128!(amount),
             relay_eon: eon,
+            asset: None,
         };
 
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps, mock_env(), info, msg)
     }
 
@@ -1359,15 +1995,16 @@ mod refund {
         to: &str,
         amount: u128,
         eon: u64,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::RefundInFull {
             id: id,
             to: addr!(to),
             amount: cu128!(amount),
             relay_eon: eon,
+            asset: None,
         };
 
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps, mock_env(), info, msg)
     }
 
@@ -1378,17 +2015,11 @@ mod refund {
         let relayer = ACC1;
         let deposited = 1000u128;
         let fet_account = ACC2;
+        let amount = DEFAULT_SWAP_LOWER_LIMIT + 10u128;
         grant_role(&mut deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
         deposit(&mut deps, deposited, DEFAULT_OWNER).unwrap();
-        swap(
-            deps.as_mut(),
-            fet_account,
-            "some_eth_account",
-            DEFAULT_SWAP_LOWER_LIMIT,
-        )
-        .unwrap();
+        swap(deps.as_mut(), fet_account, ETH_ACC1, amount).unwrap();
 
-        let amount = DEFAULT_SWAP_LOWER_LIMIT + 10u128;
         let id = 0u64;
         let eon = 0u64;
         let response: Response;
@@ -1401,7 +2032,8 @@ mod refund {
 
         // check handle response
         assert_eq!(1, response.messages.len());
-        assert_eq!(5, response.attributes.len());
+        assert_eq!(6, response.attributes.len());
+        assert_eq!(attr("denom", DEFAULT_DENUM), response.attributes[5]);
         match &response.messages[0].msg {
             CosmosMsg::Bank(bank) => match bank {
                 BankMsg::Send {
@@ -1437,16 +2069,28 @@ mod refund {
         );
 
         // check contract state
-        let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(deposited), asset.supply);
         assert_eq!(
-            cu128!(deposited - amount + DEFAULT_SWAP_LOWER_LIMIT),
-            state.supply
+            cu128!(DEFAULT_RA_ALLOWANCE - amount),
+            asset.reverse_aggregated_allowance
         );
+        assert_eq!(cu128!(fee), asset.fees_accrued);
+
+        // query
+        let query_msg = QueryMsg::Refund { id };
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        let refund: RefundResponse = from_json(response).unwrap();
         assert_eq!(
-            cu128!(DEFAULT_RA_ALLOWANCE - amount),
-            state.reverse_aggregated_allowance
+            RefundResponse {
+                refunded: true,
+                refund: Some(RefundRecord {
+                    amount: cu128!(amount),
+                    fee: cu128!(fee),
+                }),
+            },
+            refund
         );
-        assert_eq!(cu128!(fee), state.fees_accrued);
     }
 
     #[test]
@@ -1472,7 +2116,7 @@ mod refund {
         swap(
             deps.as_mut(),
             fet_account,
-            "some_eth_account",
+            ETH_ACC1,
             DEFAULT_SWAP_LOWER_LIMIT,
         )
         .unwrap();
@@ -1498,7 +2142,7 @@ mod refund {
         swap(
             deps.as_mut(),
             fet_account,
-            "some_eth_account",
+            ETH_ACC1,
             DEFAULT_SWAP_LOWER_LIMIT,
         )
         .unwrap();
@@ -1524,11 +2168,11 @@ mod refund {
         swap(
             deps.as_mut(),
             fet_account,
-            "some_eth_account",
+            ETH_ACC1,
             DEFAULT_SWAP_LOWER_LIMIT,
         )
         .unwrap();
-        let info = mock_info(DEFAULT_OWNER, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(DEFAULT_OWNER), &coins(0, DEFAULT_DENUM));
         pause_relayer_api(&mut deps, info).unwrap();
 
         let amount = DEFAULT_SWAP_LOWER_LIMIT + 10u128;
@@ -1569,12 +2213,12 @@ mod refund {
         swap(
             deps.as_mut(),
             fet_account,
-            "some_eth_account",
+            ETH_ACC1,
             DEFAULT_SWAP_LOWER_LIMIT,
         )
         .unwrap();
 
-        let amount = DEFAULT_SWAP_LOWER_LIMIT + 10u128;
+        let amount = DEFAULT_SWAP_LOWER_LIMIT;
         let id = 0u64;
         let eon = 0u64;
         refund(deps.as_mut(), relayer, id, fet_account, amount, eon).unwrap();
@@ -1582,6 +2226,63 @@ mod refund {
         expect_error!(response, ERR_ALREADY_REFUNDED);
     }
 
+    #[test]
+    fn failure_refund_amount_exceeds_swap() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let relayer = ACC1;
+        let deposited = 1000u128;
+        let fet_account = ACC2;
+        grant_role(&mut deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
+        deposit(&mut deps, deposited, DEFAULT_OWNER).unwrap();
+        swap(
+            deps.as_mut(),
+            fet_account,
+            ETH_ACC1,
+            DEFAULT_SWAP_LOWER_LIMIT,
+        )
+        .unwrap();
+
+        let amount = DEFAULT_SWAP_LOWER_LIMIT + 1u128;
+        let response = refund(deps.as_mut(), relayer, 0u64, fet_account, amount, 0u64);
+        expect_error!(response, ERR_REFUND_AMOUNT_EXCEEDED);
+
+        // partial refund is fine
+        let amount = DEFAULT_SWAP_LOWER_LIMIT - 1u128;
+        refund(deps.as_mut(), relayer, 0u64, fet_account, amount, 0u64).unwrap();
+    }
+
+    #[test]
+    fn failure_refund_in_full_amount_differs() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let relayer = ACC1;
+        let deposited = 1000u128;
+        let fet_account = ACC2;
+        grant_role(&mut deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
+        deposit(&mut deps, deposited, DEFAULT_OWNER).unwrap();
+        swap(
+            deps.as_mut(),
+            fet_account,
+            ETH_ACC1,
+            DEFAULT_SWAP_LOWER_LIMIT,
+        )
+        .unwrap();
+
+        let amount = DEFAULT_SWAP_LOWER_LIMIT - 1u128;
+        let response = refund_in_full(deps.as_mut(), relayer, 0u64, fet_account, amount, 0u64);
+        expect_error!(response, ERR_REFUND_AMOUNT_NOT_FULL);
+
+        let query_msg = QueryMsg::Refund { id: 0u64 };
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        assert_eq!(
+            "{\"refunded\":false,\"refund\":null}",
+            str_from_binary!(response)
+        );
+    }
+
     #[test]
     fn failure_refund_aggregated_reverse_allowance_exceeded() {
         let mut deps = mock_deps();
@@ -1599,8 +2300,8 @@ mod refund {
             swap(
                 deps.as_mut(),
                 fet_account,
-                "some_eth_account",
-                DEFAULT_SWAP_LOWER_LIMIT,
+                ETH_ACC1,
+                DEFAULT_SWAP_UPPER_LIMIT,
             )
             .unwrap();
             refund(
@@ -1618,8 +2319,8 @@ mod refund {
         swap(
             deps.as_mut(),
             fet_account,
-            "some_eth_account",
-            DEFAULT_SWAP_LOWER_LIMIT,
+            ETH_ACC1,
+            DEFAULT_SWAP_UPPER_LIMIT,
         )
         .unwrap();
         let response = refund(
@@ -1640,12 +2341,18 @@ mod withdraw {
     use init::init_default;
     use swap::swap;
 
-    fn withdraw(deps: DepsMut, caller: &str, amount: u128, recipient: &str) -> StdResult<Response> {
+    fn withdraw(
+        deps: DepsMut,
+        caller: &str,
+        amount: u128,
+        recipient: &str,
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::Withdraw {
             amount: cu128!(amount),
             destination: addr!(recipient),
+            asset: None,
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps, mock_env(), info, msg)
     }
 
@@ -1656,14 +2363,15 @@ mod withdraw {
 
         let amount = 1000u128;
         deposit(&mut deps, amount, DEFAULT_OWNER).unwrap();
-        swap(deps.as_mut(), "user", "some_eth_addr", amount).unwrap();
+        swap(deps.as_mut(), "user", ETH_ACC1, amount).unwrap();
 
         let recipient = ACC1;
         let response = withdraw(deps.as_mut(), DEFAULT_OWNER, 2 * amount, recipient).unwrap();
 
         // check handle response
         assert_eq!(1, response.messages.len());
-        assert_eq!(3, response.attributes.len());
+        assert_eq!(4, response.attributes.len());
+        assert_eq!(attr("denom", DEFAULT_DENUM), response.attributes[3]);
         match &response.messages[0].msg {
             CosmosMsg::Bank(bank) => match bank {
                 BankMsg::Send {
@@ -1693,8 +2401,8 @@ mod withdraw {
         );
 
         // check contract state
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(0u128), state.supply);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(0u128), asset.supply);
     }
 
     #[test]
@@ -1709,8 +2417,8 @@ mod withdraw {
         let response = withdraw(deps.as_mut(), recipient, amount, recipient);
 
         expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(amount), state.supply);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(amount), asset.supply);
     }
 
     #[test]
@@ -1730,19 +2438,21 @@ mod mint_burn {
     use super::*;
     use init::init_default;
 
-    fn mint(deps: DepsMut, caller: &str, amount: u128) -> StdResult<Response> {
+    fn mint(deps: DepsMut, caller: &str, amount: u128) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::Mint {
             amount: cu128!(amount),
+            asset: None,
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps, mock_env(), info, msg)
     }
 
-    fn burn(deps: DepsMut, caller: &str, amount: u128) -> StdResult<Response> {
+    fn burn(deps: DepsMut, caller: &str, amount: u128) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::Burn {
             amount: cu128!(amount),
+            asset: None,
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps, mock_env(), info, msg)
     }
 
@@ -1768,9 +2478,9 @@ mod mint_burn {
                 && response.attributes[1].value == amount.to_string()
         );
 
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(amount), state.supply);
-        assert_eq!(cu128!(0u128), state.fees_accrued);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(amount), asset.supply);
+        assert_eq!(cu128!(0u128), asset.fees_accrued);
     }
 
     #[test]
@@ -1797,9 +2507,9 @@ mod mint_burn {
                 && response.attributes[1].value == burn_amount.to_string()
         );
 
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(mint_amount - burn_amount), state.supply);
-        assert_eq!(cu128!(0u128), state.fees_accrued);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(mint_amount - burn_amount), asset.supply);
+        assert_eq!(cu128!(0u128), asset.fees_accrued);
     }
 
     #[test]
@@ -1852,18 +2562,19 @@ mod withdraw_fees {
         caller: &str,
         amount: u128,
         recipient: &str,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::WithdrawFees {
             amount: cu128!(amount),
             destination: addr!(recipient),
+            asset: None,
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps, mock_env(), info, msg)
     }
 
     fn _do_one_reverse_swap(
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let relayer = "relayer";
         deposit(deps, DEFAULT_SWAP_LOWER_LIMIT, DEFAULT_OWNER).unwrap();
         grant_role(deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
@@ -1872,7 +2583,7 @@ mod withdraw_fees {
             relayer,
             0u64,
             DEFAULT_OWNER,
-            "some_eth_addr",
+            ETH_ACC1,
             "HHHHHAAAASSSSH",
             DEFAULT_SWAP_LOWER_LIMIT,
             0u64,
@@ -1892,7 +2603,8 @@ mod withdraw_fees {
 
         // check handle response
         assert_eq!(1, response.messages.len());
-        assert_eq!(3, response.attributes.len());
+        assert_eq!(4, response.attributes.len());
+        assert_eq!(attr("denom", DEFAULT_DENUM), response.attributes[3]);
         match &response.messages[0].msg {
             CosmosMsg::Bank(bank) => match bank {
                 BankMsg::Send {
@@ -1923,8 +2635,8 @@ mod withdraw_fees {
         );
 
         // check contract state
-        let state = CONFIG.load(deps.as_mut().storage).unwrap();
-        assert_eq!(cu128!(0u128), state.fees_accrued);
+        let asset = ASSETS.load(deps.as_mut().storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(0u128), asset.fees_accrued);
     }
 
     #[test]
@@ -1938,8 +2650,8 @@ mod withdraw_fees {
         let response = withdraw_fees(deps.as_mut(), recipient, DEFAULT_SWAP_FEE, recipient);
 
         expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(DEFAULT_SWAP_FEE), state.fees_accrued);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(DEFAULT_SWAP_FEE), asset.fees_accrued);
     }
 
     #[test]
@@ -1958,6 +2670,28 @@ mod withdraw_fees {
         );
         expect_error!(response, ERR_SUPPLY_EXCEEDED);
     }
+
+    #[test]
+    fn success_withdraw_fees_timelocked() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        _do_one_reverse_swap(&mut deps).unwrap();
+        let height = mock_env().block.height;
+        let msg = ExecuteMsg::SetTimelockDelay { blocks: 10 };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+
+        let response = withdraw_fees(deps.as_mut(), DEFAULT_OWNER, DEFAULT_SWAP_FEE, ACC1).unwrap();
+        assert!(response.messages.is_empty());
+        assert_eq!(attr("action", "queue_operation"), response.attributes[0]);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(DEFAULT_SWAP_FEE), asset.fees_accrued);
+
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + 10, msg).unwrap();
+        assert_eq!(1, response.messages.len());
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(0u128), asset.fees_accrued);
+    }
 }
 
 mod set_cap {
@@ -1968,11 +2702,12 @@ mod set_cap {
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         caller: &str,
         amount: u128,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::SetCap {
             amount: cu128!(amount),
+            asset: None,
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
@@ -1996,11 +2731,11 @@ mod set_cap {
         );
 
         // check state
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(amount), state.cap);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(amount), asset.cap);
 
         // query
-        let query_msg = QueryMsg::Cap {};
+        let query_msg = QueryMsg::Cap { asset: None };
         let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
         assert_eq!(
             format!("{{\"amount\":\"{}\"}}", amount),
@@ -2017,8 +2752,8 @@ mod set_cap {
         let response = set_cap(&mut deps, "not_admin", amount);
 
         expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(DEFAULT_CAP), state.cap);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(DEFAULT_CAP), asset.cap);
     }
 }
 
@@ -2032,13 +2767,15 @@ mod set_limits {
         fee: u128,
         min: u128,
         max: u128,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::SetLimits {
             swap_min: cu128!(min),
             swap_max: cu128!(max),
             swap_fee: cu128!(fee),
+            fee_policy: None,
+            asset: None,
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps, mock_env(), info, msg)
     }
 
@@ -2072,13 +2809,13 @@ mod set_limits {
         );
 
         // check state
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(fee), state.swap_fee);
-        assert_eq!(cu128!(min), state.lower_swap_limit);
-        assert_eq!(cu128!(max), state.upper_swap_limit);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(fee), asset.swap_fee);
+        assert_eq!(cu128!(min), asset.lower_swap_limit);
+        assert_eq!(cu128!(max), asset.upper_swap_limit);
 
         // query
-        let query_msg = QueryMsg::SwapMax {};
+        let query_msg = QueryMsg::SwapMax { asset: None };
         let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
         assert_eq!(
             format!("{{\"amount\":\"{}\"}}", max),
@@ -2097,10 +2834,10 @@ mod set_limits {
         let response = set_limits(deps.as_mut(), "not_admin", fee, min, max);
 
         expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(DEFAULT_SWAP_FEE), state.swap_fee);
-        assert_eq!(cu128!(DEFAULT_SWAP_LOWER_LIMIT), state.lower_swap_limit);
-        assert_eq!(cu128!(DEFAULT_SWAP_UPPER_LIMIT), state.upper_swap_limit);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(DEFAULT_SWAP_FEE), asset.swap_fee);
+        assert_eq!(cu128!(DEFAULT_SWAP_LOWER_LIMIT), asset.lower_swap_limit);
+        assert_eq!(cu128!(DEFAULT_SWAP_UPPER_LIMIT), asset.upper_swap_limit);
     }
 
     #[test]
@@ -2114,10 +2851,10 @@ mod set_limits {
         let response = set_limits(deps.as_mut(), DEFAULT_OWNER, fee, min, max);
 
         expect_error!(response, ERR_SWAP_LIMITS_INCONSISTENT);
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(DEFAULT_SWAP_FEE), state.swap_fee);
-        assert_eq!(cu128!(DEFAULT_SWAP_LOWER_LIMIT), state.lower_swap_limit);
-        assert_eq!(cu128!(DEFAULT_SWAP_UPPER_LIMIT), state.upper_swap_limit);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(DEFAULT_SWAP_FEE), asset.swap_fee);
+        assert_eq!(cu128!(DEFAULT_SWAP_LOWER_LIMIT), asset.lower_swap_limit);
+        assert_eq!(cu128!(DEFAULT_SWAP_UPPER_LIMIT), asset.upper_swap_limit);
     }
 }
 
@@ -2130,11 +2867,12 @@ mod set_reverse_aggregated_allowance {
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         caller: &str,
         amount: u128,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::SetReverseAggregatedAllowance {
             amount: cu128!(amount),
+            asset: None,
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
@@ -2142,18 +2880,19 @@ mod set_reverse_aggregated_allowance {
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         caller: &str,
         amount: u128,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let msg = ExecuteMsg::SetReverseAggregatedAllowanceApproverCap {
             amount: cu128!(amount),
+            asset: None,
         };
-        let info = mock_info(caller, &coins(0, DEFAULT_DENUM));
+        let info = message_info(&addr!(caller), &coins(0, DEFAULT_DENUM));
         execute(deps.as_mut(), mock_env(), info, msg)
     }
 
     fn set_reverse_aggregated_allowance_by_approver(
         deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
         amount: u128,
-    ) -> StdResult<Response> {
+    ) -> Result<Response, ContractError> {
         let approver = "new_approver";
         grant_role(deps, APPROVER_ROLE, approver, DEFAULT_OWNER).unwrap();
         set_reverse_aggregated_allowance(deps, approver, amount)
@@ -2182,10 +2921,10 @@ mod set_reverse_aggregated_allowance {
         );
 
         // check state
-        let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
         assert_eq!(
             cu128!(amount),
-            state.reverse_aggregated_allowance_approver_cap
+            asset.reverse_aggregated_allowance_approver_cap
         );
     }
 
@@ -2210,11 +2949,11 @@ mod set_reverse_aggregated_allowance {
         );
 
         // check state
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(amount), state.reverse_aggregated_allowance);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(amount), asset.reverse_aggregated_allowance);
 
         // query
-        let query_msg = QueryMsg::ReverseAggregatedAllowance {};
+        let query_msg = QueryMsg::ReverseAggregatedAllowance { asset: None };
         let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
         assert_eq!(
             format!("{{\"amount\":\"{}\"}}", amount),
@@ -2243,11 +2982,11 @@ mod set_reverse_aggregated_allowance {
         );
 
         // check state
-        let state = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(cu128!(amount), state.reverse_aggregated_allowance);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(amount), asset.reverse_aggregated_allowance);
 
         // query
-        let query_msg = QueryMsg::ReverseAggregatedAllowance {};
+        let query_msg = QueryMsg::ReverseAggregatedAllowance { asset: None };
         let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
         assert_eq!(
             format!("{{\"amount\":\"{}\"}}", amount),
@@ -2264,10 +3003,10 @@ mod set_reverse_aggregated_allowance {
         let response = set_reverse_aggregated_allowance_by_approver(&mut deps, amount);
 
         expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
-        let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
         assert_eq!(
             cu128!(DEFAULT_RA_ALLOWANCE),
-            state.reverse_aggregated_allowance
+            asset.reverse_aggregated_allowance
         );
     }
 
@@ -2280,10 +3019,3934 @@ mod set_reverse_aggregated_allowance {
         let response = set_reverse_aggregated_allowance(&mut deps, "user", amount);
 
         expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
-        let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
         assert_eq!(
             cu128!(DEFAULT_RA_ALLOWANCE),
-            state.reverse_aggregated_allowance
+            asset.reverse_aggregated_allowance
+        );
+    }
+}
+
+mod migrate {
+    use super::*;
+    use crate::migrate::v0_3::{CONFIG_V0_3, StateV0_3};
+    use crate::migrate::{CONTRACT_NAME, CONTRACT_VERSION, migrate};
+    use crate::msg::MigrateMsg;
+    use crate::state::{CONFIG_KEY, PAUSES, PauseStart};
+    use cosmwasm_std::Storage;
+    use init::init_default;
+
+    fn store_v0_3_state(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) -> StateV0_3 {
+        let state = StateV0_3 {
+            supply: cu128!(5000u128),
+            fees_accrued: cu128!(300u128),
+            next_swap_id: 7,
+            sealed_reverse_swap_id: 3,
+            relay_eon: 2,
+            upper_swap_limit: cu128!(DEFAULT_SWAP_UPPER_LIMIT),
+            lower_swap_limit: cu128!(DEFAULT_SWAP_LOWER_LIMIT),
+            reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE),
+            reverse_aggregated_allowance_approver_cap: cu128!(DEFAULT_RA_ALLOWANCE_APPROVER_CAP),
+            cap: cu128!(DEFAULT_CAP),
+            swap_fee: cu128!(DEFAULT_SWAP_FEE),
+            paused_since_block_public_api: u64::MAX,
+            paused_since_block_relayer_api: u64::MAX,
+            denom: DEFAULT_DENUM.to_string(),
+            contract_addr_human: mock_env().contract.address,
+        };
+        CONFIG_V0_3.save(&mut deps.storage, &state).unwrap();
+        cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, "0.3.0").unwrap();
+        state
+    }
+
+    fn instantiate_msg() -> InstantiateMsg {
+        InstantiateMsg {
+            cap: cu128!(2 * DEFAULT_CAP),
+            reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE + 1),
+            reverse_aggregated_allowance_approver_cap: cu128!(
+                DEFAULT_RA_ALLOWANCE_APPROVER_CAP + 1
+            ),
+            upper_swap_limit: cu128!(DEFAULT_SWAP_UPPER_LIMIT + 1),
+            lower_swap_limit: cu128!(DEFAULT_SWAP_LOWER_LIMIT + 1),
+            swap_fee: cu128!(DEFAULT_SWAP_FEE + 1),
+            paused_since_block: Some(0),
+            denom: None,
+            next_swap_id: 1,
+            tokenfactory_dialect: None,
+        }
+    }
+
+    #[test]
+    fn success_migrate_v0_2_state() {
+        let mut deps = mock_deps();
+        let old = store_v0_3_state(&mut deps);
+        // 0.2 stores the same layout as 0.3
+        cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, "0.2.0").unwrap();
+
+        let msg = MigrateMsg {
+            forced: None,
+            instantiate_msg: None,
+            tokenfactory_dialect: None,
+        };
+        let response = migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|a| a.key == "state_migration" && a.value == "0.4.0")
+        );
+
+        // obsolete field is gone from the stored layout
+        let raw = deps.storage.get(CONFIG_KEY.as_bytes()).unwrap();
+        assert!(!str_from_binary!(raw).contains("contract_addr_human"));
+        assert!(CONFIG_V0_3.load(&deps.storage).is_err());
+
+        let state = CONFIG.load(&deps.storage).unwrap();
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(old.supply, asset.supply);
+        assert_eq!(old.fees_accrued, asset.fees_accrued);
+        assert_eq!(old.next_swap_id, state.next_swap_id);
+        assert_eq!(old.sealed_reverse_swap_id, state.sealed_reverse_swap_id);
+        assert_eq!(old.relay_eon, state.relay_eon);
+        assert_eq!(old.denom, state.denom);
+
+        let version = cw2::get_contract_version(&deps.storage).unwrap();
+        assert_eq!(CONTRACT_VERSION, version.version);
+    }
+
+    #[test]
+    fn success_migrate_v0_3_state() {
+        let mut deps = mock_deps();
+        let old = StateV0_3 {
+            supply: cu128!(5000u128),
+            fees_accrued: cu128!(300u128),
+            next_swap_id: 7,
+            sealed_reverse_swap_id: 3,
+            relay_eon: 2,
+            upper_swap_limit: cu128!(DEFAULT_SWAP_UPPER_LIMIT),
+            lower_swap_limit: cu128!(DEFAULT_SWAP_LOWER_LIMIT),
+            reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE),
+            reverse_aggregated_allowance_approver_cap: cu128!(DEFAULT_RA_ALLOWANCE_APPROVER_CAP),
+            cap: cu128!(DEFAULT_CAP),
+            swap_fee: cu128!(DEFAULT_SWAP_FEE),
+            paused_since_block_public_api: 100,
+            paused_since_block_relayer_api: u64::MAX,
+            denom: DEFAULT_DENUM.to_string(),
+            contract_addr_human: mock_env().contract.address,
+        };
+        CONFIG_V0_3.save(&mut deps.storage, &old).unwrap();
+        cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, "0.3.0").unwrap();
+
+        let msg = MigrateMsg {
+            forced: None,
+            instantiate_msg: None,
+            tokenfactory_dialect: None,
+        };
+        let response = migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|a| a.key == "state_migration" && a.value == "0.4.0")
+        );
+        let raw = deps.storage.get(CONFIG_KEY.as_bytes()).unwrap();
+        assert!(!str_from_binary!(raw).contains("contract_addr_human"));
+
+        let state = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(old.next_swap_id, state.next_swap_id);
+        assert_eq!(old.sealed_reverse_swap_id, state.sealed_reverse_swap_id);
+        assert_eq!(old.relay_eon, state.relay_eon);
+        assert_eq!(old.denom, state.denom);
+
+        // the pause of the public API moved to its operations
+        let pause = PAUSES.load(&deps.storage, "swap").unwrap();
+        assert_eq!(PauseStart::Height(100), pause.since);
+        assert_eq!(mock_env().contract.address, pause.paused_by);
+        assert!(!PAUSES.has(&deps.storage, "reverse_swap"));
+        assert!(!PAUSES.has(&deps.storage, "refund"));
+
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(old.supply, asset.supply);
+        assert_eq!(old.fees_accrued, asset.fees_accrued);
+        assert_eq!(old.cap, asset.cap);
+        assert_eq!(old.upper_swap_limit, asset.upper_swap_limit);
+        assert_eq!(old.lower_swap_limit, asset.lower_swap_limit);
+        assert_eq!(old.swap_fee, asset.swap_fee);
+        assert_eq!(
+            old.reverse_aggregated_allowance,
+            asset.reverse_aggregated_allowance
+        );
+        assert_eq!(u64::MAX, asset.paused_since_block_public_api);
+
+        // running it again is a no-op
+        crate::migrate::v0_3::migrate_state(&mut deps.storage, &mock_env()).unwrap();
+        assert_eq!(state, CONFIG.load(&deps.storage).unwrap());
+        assert_eq!(asset, ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap());
+    }
+
+    #[test]
+    fn failure_migrate_unreadable_state() {
+        let mut deps = mock_deps();
+        store_v0_3_state(&mut deps);
+        deps.storage
+            .set(CONFIG_KEY.as_bytes(), b"{\"supply\":\"5000\"}");
+
+        let response = crate::migrate::v0_3::migrate_state(&mut deps.storage, &mock_env());
+        assert!(response.unwrap_err().to_string().contains("missing field"));
+    }
+
+    #[test]
+    fn failure_migrate_semver_ordering() {
+        let mut deps = mock_deps();
+        store_v0_3_state(&mut deps);
+        // lexicographically "0.10.0" < CONTRACT_VERSION, semantically it is newer
+        cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, "0.10.0").unwrap();
+
+        let msg = MigrateMsg {
+            forced: None,
+            instantiate_msg: None,
+            tokenfactory_dialect: None,
+        };
+        let response = migrate(deps.as_mut(), mock_env(), msg);
+        assert!(response.unwrap_err().to_string().contains("Cannot upgrade"));
+    }
+
+    #[test]
+    fn failure_migrate_same_or_newer_version() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let msg = MigrateMsg {
+            forced: None,
+            instantiate_msg: None,
+            tokenfactory_dialect: None,
+        };
+        let response = migrate(deps.as_mut(), mock_env(), msg);
+        assert!(response.unwrap_err().to_string().contains("Cannot upgrade"));
+
+        cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, "10.0.0").unwrap();
+        let msg = MigrateMsg {
+            forced: None,
+            instantiate_msg: None,
+            tokenfactory_dialect: None,
+        };
+        let response = migrate(deps.as_mut(), mock_env(), msg);
+        assert!(response.unwrap_err().to_string().contains("Cannot upgrade"));
+    }
+
+    #[test]
+    fn failure_migrate_different_contract() {
+        let mut deps = mock_deps();
+        store_v0_3_state(&mut deps);
+        cw2::set_contract_version(&mut deps.storage, "other", "0.2.0").unwrap();
+
+        let msg = MigrateMsg {
+            forced: None,
+            instantiate_msg: None,
+            tokenfactory_dialect: None,
+        };
+        let response = migrate(deps.as_mut(), mock_env(), msg);
+        assert!(response.unwrap_err().to_string().contains("same type"));
+    }
+
+    #[test]
+    fn success_forced_migrate_without_version() {
+        let mut deps = mock_deps();
+        let old = store_v0_3_state(&mut deps);
+        deps.storage.remove(b"contract_info");
+
+        let msg = MigrateMsg {
+            forced: Some(true),
+            instantiate_msg: None,
+            tokenfactory_dialect: None,
+        };
+        migrate(deps.as_mut(), mock_env(), msg).unwrap();
+
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(old.supply, asset.supply);
+        assert!(CONFIG_V0_3.load(&deps.storage).is_err());
+    }
+
+    #[test]
+    fn success_forced_migrate_resets_config() {
+        let mut deps = mock_deps();
+        let old = store_v0_3_state(&mut deps);
+        let init = instantiate_msg();
+
+        let msg = MigrateMsg {
+            forced: Some(true),
+            instantiate_msg: Some(init.clone()),
+            tokenfactory_dialect: None,
+        };
+        migrate(deps.as_mut(), mock_env(), msg).unwrap();
+
+        let expected_state = State {
+            next_swap_id: old.next_swap_id,
+            sealed_reverse_swap_id: old.sealed_reverse_swap_id,
+            relay_eon: old.relay_eon,
+            denom: old.denom.clone(),
+        };
+        let expected_asset = Asset {
+            denom: old.denom,
+            kind: AssetKind::Native,
+            supply: old.supply,
+            fees_accrued: old.fees_accrued,
+            cap: init.cap,
+            upper_swap_limit: init.upper_swap_limit,
+            lower_swap_limit: init.lower_swap_limit,
+            swap_fee: init.swap_fee,
+            fee_policy: None,
+            reverse_aggregated_allowance: init.reverse_aggregated_allowance,
+            reverse_aggregated_allowance_approver_cap: init
+                .reverse_aggregated_allowance_approver_cap,
+            paused_since_block_public_api: u64::MAX,
+            paused_since_block_relayer_api: u64::MAX,
+        };
+        assert_eq!(expected_state, CONFIG.load(&deps.storage).unwrap());
+        assert_eq!(
+            expected_asset,
+            ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap()
+        );
+    }
+
+    #[test]
+    fn failure_reset_config_not_forced() {
+        let mut deps = mock_deps();
+        store_v0_3_state(&mut deps);
+
+        let msg = MigrateMsg {
+            forced: None,
+            instantiate_msg: Some(instantiate_msg()),
+            tokenfactory_dialect: None,
+        };
+        let response = migrate(deps.as_mut(), mock_env(), msg);
+        assert!(
+            response
+                .unwrap_err()
+                .to_string()
+                .contains("forced migration")
+        );
+    }
+
+    #[test]
+    fn failure_reset_config_inconsistent_limits() {
+        let mut deps = mock_deps();
+        store_v0_3_state(&mut deps);
+
+        let mut init = instantiate_msg();
+        init.swap_fee = init.lower_swap_limit;
+        let msg = MigrateMsg {
+            forced: Some(true),
+            instantiate_msg: Some(init),
+            tokenfactory_dialect: None,
+        };
+        let response = migrate(deps.as_mut(), mock_env(), msg);
+        expect_error!(response, ERR_SWAP_LIMITS_INCONSISTENT);
+    }
+}
+
+mod timelock {
+    use super::*;
+    use crate::error::{
+        ERR_TIMELOCK_NOT_READY, ERR_TIMELOCK_STALE_UNPAUSE, ERR_TIMELOCK_UNKNOWN_OPERATION,
+    };
+    use crate::msg::PendingOperationsResponse;
+    use crate::state::{PAUSES, PauseStart, QueuedOperation, TimelockedOperation};
+    use access_control::grant_role;
+    use init::init_default;
+
+    const DELAY: u64 = 10;
+
+    fn init_with_timelock(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) -> u64 {
+        init_default(deps).unwrap();
+        let height = mock_env().block.height;
+        let msg = ExecuteMsg::SetTimelockDelay { blocks: DELAY };
+        exec_at(deps, DEFAULT_OWNER, height, msg).unwrap();
+        height
+    }
+
+    fn pending_operations(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    ) -> PendingOperationsResponse {
+        let query_msg = QueryMsg::PendingOperations {
+            start_after: None,
+            limit: None,
+        };
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        from_json(&response).unwrap()
+    }
+
+    #[test]
+    fn success_queued_admin_proposal_keeps_proposer() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        grant_role(&mut deps, ADMIN_ROLE, ACC2, DEFAULT_OWNER).unwrap();
+        let height = mock_env().block.height;
+        let msg = ExecuteMsg::SetTimelockDelay { blocks: DELAY };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+
+        let msg = ExecuteMsg::ProposeAdmin {
+            address: addr!(ACC1),
+            expires_at_block: None,
+        };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+        // executed by another admin
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        exec_at(&mut deps, ACC2, height + DELAY, msg).unwrap();
+        let response =
+            exec_at(&mut deps, ACC1, height + DELAY, ExecuteMsg::AcceptAdmin {}).unwrap();
+        assert_eq!(
+            attr("previous_admin", DEFAULT_OWNER),
+            response.attributes[2]
+        );
+
+        let is_admin = |account: &str| {
+            ac_have_role(&deps.storage, &addr!(account), &AccessRole::Admin).unwrap()
+        };
+        assert!(is_admin(ACC1));
+        assert!(is_admin(ACC2));
+        assert!(!is_admin(DEFAULT_OWNER));
+    }
+
+    #[test]
+    fn success_queue_and_execute() {
+        let mut deps = mock_deps();
+        let height = init_with_timelock(&mut deps);
+
+        let amount = cu128!(DEFAULT_CAP + 1);
+        let response = exec_at(
+            &mut deps,
+            DEFAULT_OWNER,
+            height,
+            ExecuteMsg::SetCap {
+                amount,
+                asset: None,
+            },
+        )
+        .unwrap();
+        assert!(
+            response.attributes[0].key == "action"
+                && response.attributes[0].value == "queue_operation"
+        );
+        assert_eq!(
+            cu128!(DEFAULT_CAP),
+            ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap().cap
+        );
+
+        let pending = pending_operations(&deps);
+        assert_eq!(DELAY, pending.timelock_delay);
+        assert_eq!(
+            vec![QueuedOperation {
+                id: 0,
+                operation: TimelockedOperation::SetCap {
+                    amount,
+                    asset: None,
+                },
+                proposer: addr!(DEFAULT_OWNER),
+                queued_at_block: height,
+                execute_at_block: height + DELAY,
+            }],
+            pending.operations
+        );
+
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + DELAY - 1, msg.clone());
+        expect_error!(response, ERR_TIMELOCK_NOT_READY);
+
+        let response = exec_at(&mut deps, ACC1, height + DELAY, msg.clone());
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg.clone()).unwrap();
+        assert!(
+            response.attributes[0].key == "action" && response.attributes[0].value == "set_cap"
+        );
+        assert_eq!(
+            amount,
+            ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap().cap
+        );
+        assert!(pending_operations(&deps).operations.is_empty());
+
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg);
+        expect_error!(response, ERR_TIMELOCK_UNKNOWN_OPERATION);
+    }
+
+    #[test]
+    fn success_cancel_queued() {
+        let mut deps = mock_deps();
+        let height = init_with_timelock(&mut deps);
+        // granting roles is timelocked as well
+        grant_role(&mut deps, MONITOR_ROLE, ACC1, DEFAULT_OWNER).unwrap();
+        assert!(!ac_have_role(&deps.storage, &addr!(ACC1), &AccessRole::Monitor).unwrap());
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg).unwrap();
+        assert!(ac_have_role(&deps.storage, &addr!(ACC1), &AccessRole::Monitor).unwrap());
+
+        let msg = ExecuteMsg::Withdraw {
+            amount: cu128!(1u128),
+            destination: addr!(ACC2),
+            asset: None,
+        };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+
+        let msg = ExecuteMsg::CancelQueued { id: 1 };
+        let response = exec_at(&mut deps, ACC2, height, msg.clone());
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        exec_at(&mut deps, ACC1, height, msg).unwrap();
+        assert!(pending_operations(&deps).operations.is_empty());
+
+        let msg = ExecuteMsg::ExecuteQueued { id: 1 };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg);
+        expect_error!(response, ERR_TIMELOCK_UNKNOWN_OPERATION);
+    }
+
+    #[test]
+    fn success_monitor_cancels_after_revoke_queued() {
+        let mut deps = mock_deps();
+        let height = init_with_timelock(&mut deps);
+        grant_role(&mut deps, MONITOR_ROLE, MONITOR, DEFAULT_OWNER).unwrap();
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg).unwrap();
+
+        let msg = ExecuteMsg::Withdraw {
+            amount: cu128!(1u128),
+            destination: addr!(ACC2),
+            asset: None,
+        };
+        exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg).unwrap();
+        let msg = ExecuteMsg::RevokeRole {
+            role: MONITOR_ROLE.to_string(),
+            address: addr!(MONITOR),
+        };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg).unwrap();
+        assert_eq!(attr("action", "queue_operation"), response.attributes[0]);
+        assert!(ac_have_role(&deps.storage, &addr!(MONITOR), &AccessRole::Monitor).unwrap());
+
+        // the monitor still cancels both
+        for id in [1, 2] {
+            let msg = ExecuteMsg::CancelQueued { id };
+            exec_at(&mut deps, MONITOR, height + DELAY + 1, msg).unwrap();
+        }
+        assert!(pending_operations(&deps).operations.is_empty());
+        assert!(ac_have_role(&deps.storage, &addr!(MONITOR), &AccessRole::Monitor).unwrap());
+    }
+
+    #[test]
+    fn success_pause_not_timelocked() {
+        let mut deps = mock_deps();
+        let height = init_with_timelock(&mut deps);
+
+        let msg = ExecuteMsg::PausePublicApi {
+            since_block: 0,
+            asset: None,
+        };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+        let pause = PAUSES.load(&deps.storage, "swap").unwrap();
+        assert_eq!(PauseStart::Height(height), pause.since);
+
+        // unpausing is queued
+        let msg = ExecuteMsg::PausePublicApi {
+            since_block: u64::MAX,
+            asset: None,
+        };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+        let pause = PAUSES.load(&deps.storage, "swap").unwrap();
+        assert_eq!(PauseStart::Height(height), pause.since);
+
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg).unwrap();
+        let pause = PAUSES.load(&deps.storage, "swap").unwrap();
+        assert_eq!(PauseStart::Height(u64::MAX), pause.since);
+    }
+
+    #[test]
+    fn failure_stale_unpause() {
+        let mut deps = mock_deps();
+        let height = init_with_timelock(&mut deps);
+
+        let msg = ExecuteMsg::PauseRelayerApi {
+            since_block: 0,
+            asset: None,
+        };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+        let msg = ExecuteMsg::PauseRelayerApi {
+            since_block: height + DELAY,
+            asset: None,
+        };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg);
+        expect_error!(response, ERR_TIMELOCK_STALE_UNPAUSE);
+        let pause = PAUSES.load(&deps.storage, "reverse_swap").unwrap();
+        assert_eq!(PauseStart::Height(height), pause.since);
+    }
+
+    #[test]
+    fn failure_queue_invalid_operation() {
+        let mut deps = mock_deps();
+        let height = init_with_timelock(&mut deps);
+
+        let msg = ExecuteMsg::SetLimits {
+            swap_min: cu128!(DEFAULT_SWAP_FEE),
+            swap_max: cu128!(DEFAULT_SWAP_UPPER_LIMIT),
+            swap_fee: cu128!(DEFAULT_SWAP_FEE),
+            fee_policy: None,
+            asset: None,
+        };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height, msg);
+        expect_error!(response, ERR_SWAP_LIMITS_INCONSISTENT);
+
+        let msg = ExecuteMsg::SetCap {
+            amount: cu128!(1u128),
+            asset: None,
+        };
+        let response = exec_at(&mut deps, ACC1, height, msg);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        assert!(pending_operations(&deps).operations.is_empty());
+    }
+
+    #[test]
+    fn success_delay_change_timelocked() {
+        let mut deps = mock_deps();
+        let height = init_with_timelock(&mut deps);
+
+        let msg = ExecuteMsg::SetTimelockDelay { blocks: 0 };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+        assert_eq!(DELAY, pending_operations(&deps).timelock_delay);
+
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        exec_at(&mut deps, DEFAULT_OWNER, height + DELAY, msg).unwrap();
+        assert_eq!(0, pending_operations(&deps).timelock_delay);
+    }
+}
+
+mod relayer_threshold {
+    use super::*;
+    use access_control::{grant_role, revoke_role};
+    use deposit::deposit;
+    use init::init_default;
+    use refund::{refund, refund_in_full};
+    use reverse_swap::reverse_swap;
+    use swap::swap;
+
+    use crate::error::{ERR_ALREADY_ATTESTED, ERR_INVALID_RELAYER_THRESHOLD};
+    use crate::msg::PendingAttestationsResponse;
+    use crate::state::{Attestation, AttestationKind};
+
+    const RELAYERS: [&str; 3] = ["relayer_a", "relayer_b", "relayer_c"];
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn set_relayer_threshold(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        threshold: u32,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::SetRelayerThreshold { threshold };
+        let info = message_info(&addr!(DEFAULT_OWNER), &[]);
+        execute(deps.as_mut(), mock_env(), info, msg)
+    }
+
+    fn init_threshold(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, threshold: u32) {
+        init_default(deps).unwrap();
+        for relayer in RELAYERS {
+            grant_role(deps, RELAYER_ROLE, relayer, DEFAULT_OWNER).unwrap();
+        }
+        deposit(deps, 1000u128, DEFAULT_OWNER).unwrap();
+        set_relayer_threshold(deps, threshold).unwrap();
+    }
+
+    fn pending_attestations(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        kind: AttestationKind,
+    ) -> PendingAttestationsResponse {
+        let query_msg = QueryMsg::PendingAttestations {
+            kind,
+            start_after: None,
+            limit: None,
+        };
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        from_json(&response).unwrap()
+    }
+
+    #[test]
+    fn success_reverse_swap_at_threshold() {
+        let mut deps = mock_deps();
+        init_threshold(&mut deps, 2);
+
+        let amount = 200u128;
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[0],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            amount,
+            0,
+        )
+        .unwrap();
+        assert_eq!(0, response.messages.len());
+        assert!(
+            response.attributes[0].key == "action"
+                && response.attributes[0].value == "attest_reverse_swap"
+        );
+        assert_eq!(
+            cu128!(1000u128),
+            ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap().supply
+        );
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[0],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            amount,
+            0,
+        );
+        expect_error!(response, ERR_ALREADY_ATTESTED);
+
+        // a diverging attestation is tallied separately
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[1],
+            0,
+            ACC2,
+            ETH_ACC1,
+            HASH,
+            amount,
+            0,
+        )
+        .unwrap();
+        assert_eq!(0, response.messages.len());
+
+        let pending = pending_attestations(&deps, AttestationKind::ReverseSwap);
+        assert_eq!(2, pending.relayer_threshold);
+        assert_eq!(1, pending.pending.len());
+        assert_eq!(0, pending.pending[0].id);
+        assert_eq!(
+            vec![
+                Attestation {
+                    denom: DEFAULT_DENUM.to_string(),
+                    to: addr!(ACC1),
+                    amount: cu128!(amount),
+                    origin_tx_hash: Some(HASH.to_string()),
+                    relay_eon: 0,
+                    relayers: vec![addr!(RELAYERS[0])],
+                },
+                Attestation {
+                    denom: DEFAULT_DENUM.to_string(),
+                    to: addr!(ACC2),
+                    amount: cu128!(amount),
+                    origin_tx_hash: Some(HASH.to_string()),
+                    relay_eon: 0,
+                    relayers: vec![addr!(RELAYERS[1])],
+                },
+            ],
+            pending.pending[0].attestations
+        );
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[2],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            amount,
+            0,
+        )
+        .unwrap();
+        assert_eq!(1, response.messages.len());
+        assert!(
+            response.attributes[0].key == "action"
+                && response.attributes[0].value == "reverse_swap"
+        );
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(1000u128 - amount), asset.supply);
+        assert!(
+            pending_attestations(&deps, AttestationKind::ReverseSwap)
+                .pending
+                .is_empty()
+        );
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[1],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            amount,
+            0,
+        );
+        expect_error!(response, ERR_ALREADY_REVERSE_SWAPPED);
+    }
+
+    #[test]
+    fn success_refund_at_threshold() {
+        let mut deps = mock_deps();
+        init_threshold(&mut deps, 2);
+
+        let amount = 200u128;
+        swap(deps.as_mut(), ACC1, ETH_ACC1, amount).unwrap();
+
+        refund_in_full(deps.as_mut(), RELAYERS[0], 0, ACC1, amount, 0).unwrap();
+        refund(deps.as_mut(), RELAYERS[1], 0, ACC1, amount, 0).unwrap();
+        assert_eq!(
+            1,
+            pending_attestations(&deps, AttestationKind::RefundInFull)
+                .pending
+                .len()
+        );
+
+        let response = refund(deps.as_mut(), RELAYERS[2], 0, ACC1, amount, 0).unwrap();
+        assert_eq!(1, response.messages.len());
+        assert!(
+            pending_attestations(&deps, AttestationKind::Refund)
+                .pending
+                .is_empty()
+        );
+        assert!(
+            pending_attestations(&deps, AttestationKind::RefundInFull)
+                .pending
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn success_revoked_relayer_not_counted() {
+        let mut deps = mock_deps();
+        init_threshold(&mut deps, 2);
+
+        let amount = 200u128;
+        reverse_swap(
+            deps.as_mut(),
+            RELAYERS[0],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            amount,
+            0,
+        )
+        .unwrap();
+        revoke_role(&mut deps, RELAYER_ROLE, RELAYERS[0], DEFAULT_OWNER).unwrap();
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[1],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            amount,
+            0,
+        )
+        .unwrap();
+        assert_eq!(0, response.messages.len());
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[2],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            amount,
+            0,
+        )
+        .unwrap();
+        assert_eq!(1, response.messages.len());
+    }
+
+    #[test]
+    fn failure_invalid_threshold() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = set_relayer_threshold(&mut deps, 0);
+        expect_error!(response, ERR_INVALID_RELAYER_THRESHOLD);
+    }
+
+    #[test]
+    fn success_attest_anew_in_new_eon() {
+        let mut deps = mock_deps();
+        init_threshold(&mut deps, 2);
+
+        reverse_swap(
+            deps.as_mut(),
+            RELAYERS[0],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            200u128,
+            0,
+        )
+        .unwrap();
+        let msg = ExecuteMsg::NewRelayEon {};
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYERS[0]), &[]),
+            msg,
+        )
+        .unwrap();
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[0],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            200u128,
+            1,
+        )
+        .unwrap();
+        assert_eq!(0, response.messages.len());
+        let pending = pending_attestations(&deps, AttestationKind::ReverseSwap);
+        assert_eq!(1, pending.pending[0].attestations.len());
+        assert_eq!(1, pending.pending[0].attestations[0].relay_eon);
+
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[1],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            200u128,
+            1,
+        )
+        .unwrap();
+        assert_eq!(1, response.messages.len());
+    }
+
+    #[test]
+    fn success_clear_attestations() {
+        let mut deps = mock_deps();
+        init_threshold(&mut deps, 2);
+
+        // a wrong attestation keeps its relayer from attesting the transfer
+        reverse_swap(
+            deps.as_mut(),
+            RELAYERS[0],
+            0,
+            ACC2,
+            ETH_ACC1,
+            HASH,
+            200u128,
+            0,
+        )
+        .unwrap();
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[0],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            200u128,
+            0,
+        );
+        expect_error!(response, ERR_ALREADY_ATTESTED);
+
+        let msg = ExecuteMsg::ClearAttestations {
+            kind: AttestationKind::ReverseSwap,
+            id: 0,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYERS[0]), &[]),
+            msg.clone(),
+        );
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        let pending = pending_attestations(&deps, AttestationKind::ReverseSwap);
+        assert!(pending.pending.is_empty());
+
+        reverse_swap(
+            deps.as_mut(),
+            RELAYERS[0],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            200u128,
+            0,
+        )
+        .unwrap();
+        let response = reverse_swap(
+            deps.as_mut(),
+            RELAYERS[1],
+            0,
+            ACC1,
+            ETH_ACC1,
+            HASH,
+            200u128,
+            0,
+        )
+        .unwrap();
+        assert_eq!(1, response.messages.len());
+    }
+}
+
+mod outflow_limit {
+    use super::*;
+    use access_control::grant_role;
+    use deposit::deposit;
+    use init::init_default;
+    use swap::swap;
+
+    use crate::error::{ERR_OUTFLOW_LIMIT_EXCEEDED, ERR_OUTFLOW_LIMIT_INVALID};
+    use crate::msg::OutflowCapacityResponse;
+    use crate::state::{OutflowLimit, RateWindow};
+
+    const RELAYER: &str = "relayer";
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn reverse_swap_at(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        block_height: u64,
+        rid: u64,
+        amount: u128,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::ReverseSwap {
+            rid,
+            to: addr!(ACC1),
+            sender: ETH_ACC1.to_string(),
+            origin_tx_hash: HASH.to_string(),
+            amount: cu128!(amount),
+            relay_eon: 0,
+            asset: None,
+        };
+        exec_at(deps, RELAYER, block_height, msg)
+    }
+
+    fn outflow_capacity(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        block_height: u64,
+    ) -> OutflowCapacityResponse {
+        let response = query(
+            deps.as_ref(),
+            env_at(block_height),
+            QueryMsg::OutflowCapacity { asset: None },
+        )
+        .unwrap();
+        from_json(&response).unwrap()
+    }
+
+    fn init_limit(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        window: RateWindow,
+    ) -> u64 {
+        init_default(deps).unwrap();
+        grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+        deposit(deps, 5000u128, DEFAULT_OWNER).unwrap();
+
+        let height = mock_env().block.height;
+        let msg = ExecuteMsg::SetOutflowLimit {
+            limit: Some(OutflowLimit {
+                max_outflow: cu128!(300u128),
+                window,
+            }),
+            asset: None,
+        };
+        exec_at(deps, DEFAULT_OWNER, height, msg).unwrap();
+        height
+    }
+
+    #[test]
+    fn success_outflow_refills() {
+        let mut deps = mock_deps();
+        let height = init_limit(&mut deps, RateWindow::Blocks(100));
+
+        let capacity = outflow_capacity(&deps, height);
+        assert_eq!(Some(cu128!(300u128)), capacity.available);
+        assert_eq!(Some(height), capacity.full_at);
+
+        reverse_swap_at(&mut deps, height, 0, 200).unwrap();
+        let response = reverse_swap_at(&mut deps, height, 1, 200);
+        expect_error!(response, ERR_OUTFLOW_LIMIT_EXCEEDED);
+
+        let capacity = outflow_capacity(&deps, height);
+        assert_eq!(Some(cu128!(100u128)), capacity.available);
+        assert_eq!(Some(height + 67), capacity.full_at);
+
+        // 50 blocks refill half of the max outflow
+        assert_eq!(
+            Some(cu128!(250u128)),
+            outflow_capacity(&deps, height + 50).available
+        );
+        reverse_swap_at(&mut deps, height + 50, 1, 200).unwrap();
+        assert_eq!(
+            Some(cu128!(300u128)),
+            outflow_capacity(&deps, height + 1000).available
+        );
+    }
+
+    #[test]
+    fn success_outflow_refund_and_seconds_window() {
+        let mut deps = mock_deps();
+        let height = init_limit(&mut deps, RateWindow::Seconds(500));
+
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 1000u128).unwrap();
+        let msg = ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC1),
+            amount: cu128!(1000u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        let response = exec_at(&mut deps, RELAYER, height, msg.clone());
+        expect_error!(response, ERR_OUTFLOW_LIMIT_EXCEEDED);
+
+        reverse_swap_at(&mut deps, height, 0, 300).unwrap();
+        // 20 blocks are 100 seconds
+        assert_eq!(
+            Some(cu128!(60u128)),
+            outflow_capacity(&deps, height + 20).available
+        );
+    }
+
+    #[test]
+    fn success_disable_outflow_limit() {
+        let mut deps = mock_deps();
+        let height = init_limit(&mut deps, RateWindow::Blocks(100));
+        reverse_swap_at(&mut deps, height, 0, 300).unwrap();
+
+        let msg = ExecuteMsg::SetOutflowLimit {
+            limit: None,
+            asset: None,
+        };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+        assert_eq!(None, outflow_capacity(&deps, height).available);
+        reverse_swap_at(&mut deps, height, 1, 300).unwrap();
+    }
+
+    #[test]
+    fn failure_set_outflow_limit() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        let height = mock_env().block.height;
+
+        let limit = Some(OutflowLimit {
+            max_outflow: cu128!(300u128),
+            window: RateWindow::Blocks(0),
+        });
+        let msg = ExecuteMsg::SetOutflowLimit {
+            limit: limit.clone(),
+            asset: None,
+        };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height, msg);
+        expect_error!(response, ERR_OUTFLOW_LIMIT_INVALID);
+
+        let msg = ExecuteMsg::SetOutflowLimit { limit, asset: None };
+        let response = exec_at(&mut deps, ACC1, height, msg);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+    }
+}
+
+mod sender_limits {
+    use super::*;
+    use init::init_default;
+
+    use crate::error::{
+        ERR_SENDER_LIMITS_INVALID, ERR_SENDER_SWAP_COUNT_EXCEEDED, ERR_SENDER_VOLUME_EXCEEDED,
+    };
+    use crate::msg::SenderUsageResponse;
+    use crate::state::{RateWindow, SenderLimits};
+
+    fn swap_at(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        from: &str,
+        block_height: u64,
+        amount: u128,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Swap {
+            destination: ETH_ACC1.to_string(),
+            asset: None,
+        };
+        let info = message_info(&addr!(from), &coins(amount, DEFAULT_DENUM));
+        execute(deps.as_mut(), env_at(block_height), info, msg)
+    }
+
+    fn sender_usage(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+        block_height: u64,
+    ) -> SenderUsageResponse {
+        let msg = QueryMsg::SenderUsage {
+            address: addr!(address),
+            asset: None,
+        };
+        let response = query(deps.as_ref(), env_at(block_height), msg).unwrap();
+        from_json(&response).unwrap()
+    }
+
+    fn set_sender_limits(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        caller: &str,
+        limits: Option<SenderLimits>,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::SetSenderLimits {
+            limits,
+            asset: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(caller), &[]),
+            msg,
+        )
+    }
+
+    fn init_limits(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        max_volume: Option<u128>,
+        max_swaps: Option<u32>,
+    ) -> u64 {
+        init_default(deps).unwrap();
+        let limits = SenderLimits {
+            window: RateWindow::Blocks(100),
+            max_volume: max_volume.map(Uint128::new),
+            max_swaps,
+        };
+        set_sender_limits(deps, DEFAULT_OWNER, Some(limits)).unwrap();
+        mock_env().block.height
+    }
+
+    #[test]
+    fn success_sender_volume_resets_with_window() {
+        let mut deps = mock_deps();
+        let height = init_limits(&mut deps, Some(1500), None);
+
+        swap_at(&mut deps, ACC1, height, 1000).unwrap();
+        let response = swap_at(&mut deps, ACC1, height + 10, 600);
+        expect_error!(response, ERR_SENDER_VOLUME_EXCEEDED);
+        // other senders are tracked separately
+        swap_at(&mut deps, ACC2, height + 10, 600).unwrap();
+        swap_at(&mut deps, ACC1, height + 10, 500).unwrap();
+
+        let usage = sender_usage(&deps, ACC1, height + 10);
+        assert_eq!(cu128!(1500u128), usage.volume);
+        assert_eq!(2, usage.swaps);
+        assert_eq!(Some(height + 100), usage.resets_at);
+
+        let usage = sender_usage(&deps, ACC1, height + 100);
+        assert_eq!(Uint128::zero(), usage.volume);
+        assert_eq!(0, usage.swaps);
+        swap_at(&mut deps, ACC1, height + 100, 1000).unwrap();
+    }
+
+    #[test]
+    fn success_sender_swap_count() {
+        let mut deps = mock_deps();
+        let height = init_limits(&mut deps, None, Some(2));
+
+        swap_at(&mut deps, ACC1, height, 200).unwrap();
+        swap_at(&mut deps, ACC1, height, 200).unwrap();
+        let response = swap_at(&mut deps, ACC1, height + 99, 200);
+        expect_error!(response, ERR_SENDER_SWAP_COUNT_EXCEEDED);
+        swap_at(&mut deps, ACC1, height + 100, 200).unwrap();
+    }
+
+    #[test]
+    fn success_disable_sender_limits() {
+        let mut deps = mock_deps();
+        let height = init_limits(&mut deps, Some(1000), Some(1));
+        swap_at(&mut deps, ACC1, height, 1000).unwrap();
+
+        set_sender_limits(&mut deps, DEFAULT_OWNER, None).unwrap();
+        let usage = sender_usage(&deps, ACC1, height);
+        assert_eq!(None, usage.limits);
+        assert_eq!(None, usage.resets_at);
+        swap_at(&mut deps, ACC1, height, 1000).unwrap();
+    }
+
+    #[test]
+    fn failure_set_sender_limits() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let limits = Some(SenderLimits {
+            window: RateWindow::Seconds(0),
+            max_volume: None,
+            max_swaps: Some(1),
+        });
+        let response = set_sender_limits(&mut deps, DEFAULT_OWNER, limits.clone());
+        expect_error!(response, ERR_SENDER_LIMITS_INVALID);
+
+        let response = set_sender_limits(&mut deps, ACC1, limits);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+    }
+}
+
+mod fee_policy {
+    use super::*;
+    use access_control::grant_role;
+    use deposit::deposit;
+    use init::init_default;
+    use reverse_swap::reverse_swap;
+    use swap::swap;
+
+    use crate::error::ERR_INVALID_FEE_POLICY;
+    use crate::state::{FeePolicy, FeeTier};
+
+    const RELAYER: &str = "relayer";
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn set_fee_policy(
+        deps: DepsMut,
+        min: u128,
+        max: u128,
+        fee_policy: Option<FeePolicy>,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::SetLimits {
+            swap_min: cu128!(min),
+            swap_max: cu128!(max),
+            swap_fee: cu128!(DEFAULT_SWAP_FEE),
+            fee_policy,
+            asset: None,
+        };
+        execute(
+            deps,
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+    }
+
+    fn init_with_supply(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+        init_default(deps).unwrap();
+        grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+        deposit(deps, 10000u128, DEFAULT_OWNER).unwrap();
+    }
+
+    fn charged_fee(response: &Response) -> String {
+        response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "swap_fee" || attr.key == "refund_fee")
+            .unwrap()
+            .value
+            .clone()
+    }
+
+    #[test]
+    fn success_basis_points_fee_clamped() {
+        let mut deps = mock_deps();
+        init_with_supply(&mut deps);
+
+        // 1%, at least 5 and at most 20
+        let fee_policy = FeePolicy::BasisPoints {
+            bps: 100,
+            min_fee: cu128!(5u128),
+            max_fee: Some(cu128!(20u128)),
+        };
+        let response = set_fee_policy(deps.as_mut(), 10, 5000, Some(fee_policy.clone())).unwrap();
+        assert!(response.attributes.contains(&attr("fee_policy", "100 bps")));
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(Some(fee_policy), asset.fee_policy);
+
+        for (rid, amount, fee) in [(0, 100u128, 5u128), (1, 1000, 10), (2, 5000, 20)] {
+            let response =
+                reverse_swap(deps.as_mut(), RELAYER, rid, ACC1, ETH_ACC1, HASH, amount, 0).unwrap();
+            assert_eq!(fee.to_string(), charged_fee(&response));
+            assert!(
+                response
+                    .attributes
+                    .contains(&attr("amount", (amount - fee).to_string()))
+            );
+        }
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(35u128), asset.fees_accrued);
+    }
+
+    #[test]
+    fn success_tiered_fee_on_refund() {
+        let mut deps = mock_deps();
+        init_with_supply(&mut deps);
+
+        let tiers = vec![
+            FeeTier {
+                min_amount: cu128!(0u128),
+                fee: cu128!(50u128),
+            },
+            FeeTier {
+                min_amount: cu128!(500u128),
+                fee: cu128!(80u128),
+            },
+        ];
+        set_fee_policy(deps.as_mut(), 100, 1000, Some(FeePolicy::Tiered { tiers })).unwrap();
+
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 400u128).unwrap();
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 600u128).unwrap();
+        for (id, amount, fee) in [(0, 400u128, 50u128), (1, 600, 80)] {
+            let msg = ExecuteMsg::Refund {
+                id,
+                to: addr!(ACC1),
+                amount: cu128!(amount),
+                relay_eon: 0,
+                asset: None,
+            };
+            let response = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&addr!(RELAYER), &[]),
+                msg,
+            );
+            assert_eq!(fee.to_string(), charged_fee(&response.unwrap()));
+        }
+
+        // back to the flat swap fee
+        set_fee_policy(deps.as_mut(), 110, 1000, None).unwrap();
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 600u128, 0).unwrap();
+        assert_eq!(DEFAULT_SWAP_FEE.to_string(), charged_fee(&response));
+    }
+
+    #[test]
+    fn failure_fee_policy_inconsistent_with_limits() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        // min_fee takes the whole swap_min
+        let fee_policy = FeePolicy::BasisPoints {
+            bps: 100,
+            min_fee: cu128!(10u128),
+            max_fee: None,
+        };
+        let response = set_fee_policy(deps.as_mut(), 10, 5000, Some(fee_policy));
+        expect_error!(response, ERR_SWAP_LIMITS_INCONSISTENT);
+
+        // the upper tier fee takes the whole amount at the tier boundary
+        let tiers = vec![
+            FeeTier {
+                min_amount: cu128!(0u128),
+                fee: cu128!(5u128),
+            },
+            FeeTier {
+                min_amount: cu128!(100u128),
+                fee: cu128!(100u128),
+            },
+        ];
+        let fee_policy = Some(FeePolicy::Tiered { tiers });
+        let response = set_fee_policy(deps.as_mut(), 10, 5000, fee_policy.clone());
+        expect_error!(response, ERR_SWAP_LIMITS_INCONSISTENT);
+        // fine as long as the boundary is out of the limits
+        set_fee_policy(deps.as_mut(), 10, 99, fee_policy).unwrap();
+    }
+
+    #[test]
+    fn failure_invalid_fee_policy() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let invalid_policies = vec![
+            FeePolicy::BasisPoints {
+                bps: 10001,
+                min_fee: cu128!(0u128),
+                max_fee: None,
+            },
+            FeePolicy::BasisPoints {
+                bps: 100,
+                min_fee: cu128!(5u128),
+                max_fee: Some(cu128!(4u128)),
+            },
+            FeePolicy::Tiered { tiers: vec![] },
+            FeePolicy::Tiered {
+                tiers: vec![FeeTier {
+                    min_amount: cu128!(10u128),
+                    fee: cu128!(1u128),
+                }],
+            },
+            FeePolicy::Tiered {
+                tiers: vec![
+                    FeeTier {
+                        min_amount: cu128!(0u128),
+                        fee: cu128!(1u128),
+                    },
+                    FeeTier {
+                        min_amount: cu128!(0u128),
+                        fee: cu128!(2u128),
+                    },
+                ],
+            },
+        ];
+        for fee_policy in invalid_policies {
+            let response = set_fee_policy(deps.as_mut(), 110, 1000, Some(fee_policy));
+            expect_error!(response, ERR_INVALID_FEE_POLICY);
+        }
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(None, asset.fee_policy);
+    }
+}
+
+mod dust_policy {
+    use super::*;
+    use access_control::grant_role;
+    use deposit::deposit;
+    use init::init_default;
+    use reverse_swap::reverse_swap;
+    use swap::swap;
+
+    use crate::error::{ERR_DUST_REJECTED, ERR_NOTHING_TO_CLAIM};
+    use crate::msg::{ClaimableResponse, DustPolicyResponse};
+    use crate::state::DustPolicy;
+
+    const RELAYER: &str = "relayer";
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn init_with_policy(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        policy: DustPolicy,
+    ) {
+        init_default(deps).unwrap();
+        grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+        deposit(deps, 10000u128, DEFAULT_OWNER).unwrap();
+
+        let msg = ExecuteMsg::SetDustPolicy { policy };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
+    fn claimable(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, address: &str) -> Uint128 {
+        let msg = QueryMsg::Claimable {
+            address: addr!(address),
+        };
+        let response: ClaimableResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        response
+            .claimable
+            .iter()
+            .find(|coin| coin.denom == DEFAULT_DENUM)
+            .map_or(Uint128::zero(), |coin| coin.amount)
+    }
+
+    fn claim(deps: DepsMut, caller: &str) -> Result<Response, ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&addr!(caller), &[]),
+            ExecuteMsg::Claim {},
+        )
+    }
+
+    #[test]
+    fn success_default_confiscates_dust() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        grant_role(&mut deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+        deposit(&mut deps, 10000u128, DEFAULT_OWNER).unwrap();
+
+        let response: DustPolicyResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::DustPolicy {}).unwrap()).unwrap();
+        assert_eq!(DustPolicy::Confiscate, response.policy);
+
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 100u128, 0).unwrap();
+        assert_eq!(0, response.messages.len());
+        assert_eq!("dust_confiscated", response.events[0].ty);
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(100u128), asset.fees_accrued);
+        assert_eq!(Uint128::zero(), claimable(&deps, ACC1));
+    }
+
+    #[test]
+    fn success_reject_dust() {
+        let mut deps = mock_deps();
+        init_with_policy(&mut deps, DustPolicy::Reject);
+
+        let response = reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 100u128, 0);
+        expect_error!(response, ERR_DUST_REJECTED);
+        let query_msg = QueryMsg::ReverseSwapProcessed { rid: 0 };
+        let response = query(deps.as_ref(), mock_env(), query_msg).unwrap();
+        assert_eq!("{\"processed\":false}", str_from_binary!(response));
+
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 200u128).unwrap();
+        let msg = ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC1),
+            amount: cu128!(DEFAULT_SWAP_FEE),
+            relay_eon: 0,
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        );
+        expect_error!(response, ERR_DUST_REJECTED);
+
+        // above the fee goes through as usual
+        reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 101u128, 0).unwrap();
+    }
+
+    #[test]
+    fn success_hold_and_claim_dust() {
+        let mut deps = mock_deps();
+        init_with_policy(&mut deps, DustPolicy::Hold);
+
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 60u128, 0).unwrap();
+        assert_eq!(0, response.messages.len());
+        let event = &response.events[0];
+        assert_eq!("dust_held", event.ty);
+        assert!(event.attributes.contains(&attr("amount", "60")));
+
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 200u128).unwrap();
+        let msg = ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC1),
+            amount: cu128!(40u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!("dust_held", response.events[0].ty);
+
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(Uint128::zero(), asset.fees_accrued);
+        assert_eq!(cu128!(10100u128), asset.supply);
+        assert_eq!(cu128!(100u128), claimable(&deps, ACC1));
+
+        let response = claim(deps.as_mut(), ACC1).unwrap();
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ACC1.to_string(),
+                amount: coins(100u128, DEFAULT_DENUM),
+            })
+        );
+        assert_eq!(Uint128::zero(), claimable(&deps, ACC1));
+        expect_error!(claim(deps.as_mut(), ACC1), ERR_NOTHING_TO_CLAIM);
+    }
+
+    #[test]
+    fn failure_set_dust_policy_not_admin() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let msg = ExecuteMsg::SetDustPolicy {
+            policy: DustPolicy::Hold,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(ACC1), &[]),
+            msg,
+        );
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+    }
+}
+
+mod assets {
+    use super::*;
+    use access_control::grant_role;
+    use init::init_default;
+    use swap::swap;
+
+    use crate::error::{ERR_ASSET_ALREADY_EXISTS, ERR_ASSET_MISMATCH, ERR_UNKNOWN_ASSET};
+    use crate::msg::{FullStateResponse, ListAssetsResponse};
+
+    const OTHER_DENOM: &str = "uother";
+    const RELAYER: &str = "relayer";
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn add_asset(deps: DepsMut, caller: &str, denom: &str) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::AddAsset {
+            denom: denom.to_string(),
+            kind: None,
+            cap: cu128!(DEFAULT_CAP),
+            upper_swap_limit: cu128!(DEFAULT_SWAP_UPPER_LIMIT),
+            lower_swap_limit: cu128!(DEFAULT_SWAP_LOWER_LIMIT),
+            swap_fee: cu128!(10u128),
+            reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE),
+            reverse_aggregated_allowance_approver_cap: cu128!(DEFAULT_RA_ALLOWANCE_APPROVER_CAP),
+            paused_since_block: None,
+        };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn swap_other(deps: DepsMut, from: &str, amount: u128) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Swap {
+            destination: ETH_ACC1.to_string(),
+            asset: Some(OTHER_DENOM.to_string()),
+        };
+        execute(
+            deps,
+            mock_env(),
+            message_info(&addr!(from), &coins(amount, OTHER_DENOM)),
+            msg,
+        )
+    }
+
+    fn setup(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+        init_default(deps).unwrap();
+        add_asset(deps.as_mut(), DEFAULT_OWNER, OTHER_DENOM).unwrap();
+        grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+    }
+
+    fn asset(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, denom: &str) -> Asset {
+        ASSETS.load(&deps.storage, denom).unwrap()
+    }
+
+    #[test]
+    fn success_add_asset() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = add_asset(deps.as_mut(), DEFAULT_OWNER, OTHER_DENOM).unwrap();
+        assert_eq!(attr("action", "add_asset"), response.attributes[0]);
+        assert_eq!(attr("denom", OTHER_DENOM), response.attributes[1]);
+
+        let msg = QueryMsg::Asset {
+            denom: OTHER_DENOM.to_string(),
+        };
+        let added: Asset = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(Uint128::zero(), added.supply);
+        assert_eq!(cu128!(10u128), added.swap_fee);
+        assert_eq!(u64::MAX, added.paused_since_block_public_api);
+
+        let msg = QueryMsg::ListAssets {
+            start_after: None,
+            limit: None,
+        };
+        let response: ListAssetsResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let denoms: Vec<_> = response.assets.iter().map(|a| a.denom.as_str()).collect();
+        assert_eq!(vec![DEFAULT_DENUM, OTHER_DENOM], denoms);
+
+        let msg = QueryMsg::ListAssets {
+            start_after: Some(DEFAULT_DENUM.to_string()),
+            limit: None,
+        };
+        let response: ListAssetsResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(1, response.assets.len());
+        assert_eq!(OTHER_DENOM, response.assets[0].denom);
+    }
+
+    #[test]
+    fn success_full_state_of_default_asset() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+        swap_other(deps.as_mut(), ACC1, 300u128).unwrap();
+
+        let msg = QueryMsg::FullState {};
+        let state: FullStateResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let default = asset(&deps, DEFAULT_DENUM);
+        assert_eq!(DEFAULT_DENUM, state.denom);
+        assert_eq!(default.supply, state.supply);
+        assert_eq!(default.fees_accrued, state.fees_accrued);
+        assert_eq!(default.cap, state.cap);
+        assert_eq!(default.swap_fee, state.swap_fee);
+        assert_eq!(
+            default.reverse_aggregated_allowance,
+            state.reverse_aggregated_allowance
+        );
+        assert_eq!(2, state.next_swap_id);
+        assert_eq!(u64::MAX, state.paused_since_block_public_api);
+    }
+
+    #[test]
+    fn failure_add_asset() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        expect_error!(
+            add_asset(deps.as_mut(), ACC1, OTHER_DENOM),
+            ERR_ACCESS_CONTROL_ONLY_ADMIN
+        );
+        expect_error!(
+            add_asset(deps.as_mut(), DEFAULT_OWNER, DEFAULT_DENUM),
+            ERR_ASSET_ALREADY_EXISTS
+        );
+    }
+
+    #[test]
+    fn success_swap_accounted_per_asset() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let response = swap_other(deps.as_mut(), ACC1, 500u128).unwrap();
+        assert_eq!(attr("denom", OTHER_DENOM), response.attributes[4]);
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 200u128).unwrap();
+
+        assert_eq!(cu128!(500u128), asset(&deps, OTHER_DENOM).supply);
+        assert_eq!(cu128!(200u128), asset(&deps, DEFAULT_DENUM).supply);
+
+        // swap ids are shared by the assets
+        let msg = QueryMsg::Swap { id: 0 };
+        let swap: SwapResponse = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(OTHER_DENOM, swap.denom);
+        let msg = QueryMsg::Swap { id: 1 };
+        let swap: SwapResponse = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(DEFAULT_DENUM, swap.denom);
+
+        let msg = QueryMsg::Supply {
+            asset: Some(OTHER_DENOM.to_string()),
+        };
+        let response = query(deps.as_ref(), mock_env(), msg).unwrap();
+        assert_eq!("{\"amount\":\"500\"}", str_from_binary!(response));
+    }
+
+    #[test]
+    fn failure_swap_wrong_funds_or_unknown_asset() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        // funds of the default asset sent to swap the other one
+        let msg = ExecuteMsg::Swap {
+            destination: ETH_ACC1.to_string(),
+            asset: Some(OTHER_DENOM.to_string()),
+        };
+        let info = message_info(&addr!(ACC1), &coins(500u128, DEFAULT_DENUM));
+        expect_error!(
+            execute(deps.as_mut(), mock_env(), info, msg),
+            ERR_UNRECOGNIZED_DENOM
+        );
+
+        let msg = ExecuteMsg::Swap {
+            destination: ETH_ACC1.to_string(),
+            asset: Some("unknown".to_string()),
+        };
+        let info = message_info(&addr!(ACC1), &coins(500u128, "unknown"));
+        expect_error!(
+            execute(deps.as_mut(), mock_env(), info, msg),
+            ERR_UNKNOWN_ASSET
+        );
+    }
+
+    #[test]
+    fn success_reverse_swap_pays_out_asset() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        swap_other(deps.as_mut(), ACC1, 500u128).unwrap();
+
+        let msg = ExecuteMsg::ReverseSwap {
+            rid: 0,
+            to: addr!(ACC2),
+            sender: ETH_ACC1.to_string(),
+            origin_tx_hash: HASH.to_string(),
+            amount: cu128!(300u128),
+            relay_eon: 0,
+            asset: Some(OTHER_DENOM.to_string()),
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ACC2.to_string(),
+                amount: coins(290u128, OTHER_DENOM),
+            })
+        );
+
+        let other = asset(&deps, OTHER_DENOM);
+        assert_eq!(cu128!(200u128), other.supply);
+        assert_eq!(cu128!(10u128), other.fees_accrued);
+        assert_eq!(
+            cu128!(DEFAULT_RA_ALLOWANCE - 300),
+            other.reverse_aggregated_allowance
+        );
+        let default = asset(&deps, DEFAULT_DENUM);
+        assert_eq!(Uint128::zero(), default.fees_accrued);
+        assert_eq!(
+            cu128!(DEFAULT_RA_ALLOWANCE),
+            default.reverse_aggregated_allowance
+        );
+    }
+
+    #[test]
+    fn success_refund_follows_swap_asset() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        swap_other(deps.as_mut(), ACC1, 500u128).unwrap();
+
+        let refund = |asset: Option<&str>| ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC1),
+            amount: cu128!(500u128),
+            relay_eon: 0,
+            asset: asset.map(str::to_string),
+        };
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            refund(Some(DEFAULT_DENUM)),
+        );
+        expect_error!(response, ERR_ASSET_MISMATCH);
+
+        // the asset of the swap is used when none is given
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            refund(None),
+        )
+        .unwrap();
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ACC1.to_string(),
+                amount: coins(490u128, OTHER_DENOM),
+            })
+        );
+        assert_eq!(Uint128::zero(), asset(&deps, OTHER_DENOM).supply);
+    }
+
+    #[test]
+    fn success_pause_single_asset() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let msg = ExecuteMsg::PausePublicApi {
+            since_block: 0,
+            asset: Some(OTHER_DENOM.to_string()),
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(attr("asset", OTHER_DENOM), response.attributes[2]);
+
+        expect_error!(
+            swap_other(deps.as_mut(), ACC1, 500u128),
+            ERR_CONTRACT_PAUSED
+        );
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+
+        let height = mock_env().block.height;
+        let msg = QueryMsg::PausedPublicApiSince {
+            asset: Some(OTHER_DENOM.to_string()),
+        };
+        let response = query(deps.as_ref(), mock_env(), msg).unwrap();
+        assert_eq!(
+            format!("{{\"block\":{}}}", height),
+            str_from_binary!(response)
+        );
+        let msg = QueryMsg::PausedPublicApiSince { asset: None };
+        let response = query(deps.as_ref(), mock_env(), msg).unwrap();
+        assert_eq!(
+            format!("{{\"block\":{}}}", u64::MAX),
+            str_from_binary!(response)
+        );
+    }
+}
+
+mod cw20 {
+    use super::*;
+    use access_control::grant_role;
+    use init::init_default;
+
+    use crate::error::{ERR_ASSET_KIND, ERR_UNKNOWN_ASSET};
+    use crate::msg::ReceiveMsg;
+    use ::cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+    use cosmwasm_std::{WasmMsg, to_json_binary};
+
+    const RELAYER: &str = "relayer";
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn token(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> String {
+        deps.api.addr_make("token").to_string()
+    }
+
+    fn setup(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) -> String {
+        init_default(deps).unwrap();
+        grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+
+        let token = token(deps);
+        let msg = ExecuteMsg::AddAsset {
+            denom: token.clone(),
+            kind: Some(AssetKind::Cw20),
+            cap: cu128!(DEFAULT_CAP),
+            upper_swap_limit: cu128!(DEFAULT_SWAP_UPPER_LIMIT),
+            lower_swap_limit: cu128!(DEFAULT_SWAP_LOWER_LIMIT),
+            swap_fee: cu128!(DEFAULT_SWAP_FEE),
+            reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE),
+            reverse_aggregated_allowance_approver_cap: cu128!(DEFAULT_RA_ALLOWANCE_APPROVER_CAP),
+            paused_since_block: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        token
+    }
+
+    fn receive(
+        deps: DepsMut,
+        token: &str,
+        sender: &str,
+        amount: u128,
+        msg: ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount: cu128!(amount),
+            msg: to_json_binary(&msg).unwrap(),
+        });
+        execute(deps, mock_env(), message_info(&addr!(token), &[]), msg)
+    }
+
+    fn cw20_transfer(token: &str, recipient: &str, amount: u128) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount: cu128!(amount),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    }
+
+    fn swap_msg() -> ReceiveMsg {
+        ReceiveMsg::Swap {
+            destination: ETH_ACC1.to_string(),
+        }
+    }
+
+    #[test]
+    fn success_receive_swap() {
+        let mut deps = mock_deps();
+        let token = setup(&mut deps);
+
+        let response = receive(deps.as_mut(), &token, ACC1, 500u128, swap_msg()).unwrap();
+        assert_eq!(attr("action", "swap"), response.attributes[0]);
+        assert_eq!(attr("denom", token.as_str()), response.attributes[4]);
+
+        assert_eq!(
+            cu128!(500u128),
+            ASSETS.load(&deps.storage, &token).unwrap().supply
+        );
+        let msg = QueryMsg::Swap { id: 0 };
+        let swap: SwapResponse = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(addr!(ACC1), swap.sender);
+        assert_eq!(token, swap.denom);
+    }
+
+    #[test]
+    fn failure_receive_swap_checks() {
+        let mut deps = mock_deps();
+        let token = setup(&mut deps);
+
+        let response = receive(deps.as_mut(), &token, ACC1, 10u128, swap_msg());
+        expect_error!(response, ERR_SWAP_LIMITS_VIOLATED);
+
+        let msg = ExecuteMsg::PausePublicApi {
+            since_block: 0,
+            asset: Some(token.clone()),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        let response = receive(deps.as_mut(), &token, ACC1, 500u128, swap_msg());
+        expect_error!(response, ERR_CONTRACT_PAUSED);
+
+        // only registered CW20 contracts are accepted
+        let unknown = deps.api.addr_make("unknown").to_string();
+        let response = receive(deps.as_mut(), &unknown, ACC1, 500u128, swap_msg());
+        expect_error!(response, ERR_UNKNOWN_ASSET);
+        let response = receive(deps.as_mut(), DEFAULT_DENUM, ACC1, 500u128, swap_msg());
+        expect_error!(response, ERR_ASSET_KIND);
+    }
+
+    #[test]
+    fn failure_native_funds_for_cw20_asset() {
+        let mut deps = mock_deps();
+        let token = setup(&mut deps);
+
+        let msg = ExecuteMsg::Swap {
+            destination: ETH_ACC1.to_string(),
+            asset: Some(token.clone()),
+        };
+        let info = message_info(&addr!(ACC1), &coins(500u128, token.as_str()));
+        expect_error!(
+            execute(deps.as_mut(), mock_env(), info, msg),
+            ERR_ASSET_KIND
+        );
+    }
+
+    #[test]
+    fn success_receive_deposit() {
+        let mut deps = mock_deps();
+        let token = setup(&mut deps);
+
+        let response = receive(
+            deps.as_mut(),
+            &token,
+            ACC1,
+            5000u128,
+            ReceiveMsg::Deposit {},
+        );
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        receive(
+            deps.as_mut(),
+            &token,
+            DEFAULT_OWNER,
+            5000u128,
+            ReceiveMsg::Deposit {},
+        )
+        .unwrap();
+        assert_eq!(
+            cu128!(5000u128),
+            ASSETS.load(&deps.storage, &token).unwrap().supply
+        );
+    }
+
+    #[test]
+    fn success_cw20_payouts() {
+        let mut deps = mock_deps();
+        let token = setup(&mut deps);
+        receive(
+            deps.as_mut(),
+            &token,
+            DEFAULT_OWNER,
+            5000u128,
+            ReceiveMsg::Deposit {},
+        )
+        .unwrap();
+        receive(deps.as_mut(), &token, ACC1, 500u128, swap_msg()).unwrap();
+
+        let msg = ExecuteMsg::ReverseSwap {
+            rid: 0,
+            to: addr!(ACC2),
+            sender: ETH_ACC1.to_string(),
+            origin_tx_hash: HASH.to_string(),
+            amount: cu128!(300u128),
+            relay_eon: 0,
+            asset: Some(token.clone()),
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(response.messages[0].msg, cw20_transfer(&token, ACC2, 200));
+
+        let msg = ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC1),
+            amount: cu128!(500u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(response.messages[0].msg, cw20_transfer(&token, ACC1, 400));
+
+        let msg = ExecuteMsg::Withdraw {
+            amount: cu128!(1000u128),
+            destination: addr!(DEFAULT_OWNER),
+            asset: Some(token.clone()),
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(
+            response.messages[0].msg,
+            cw20_transfer(&token, DEFAULT_OWNER, 1000)
+        );
+
+        let asset = ASSETS.load(&deps.storage, &token).unwrap();
+        assert_eq!(cu128!(5500u128 - 300 - 500 - 1000), asset.supply);
+        assert_eq!(cu128!(200u128), asset.fees_accrued);
+    }
+}
+
+mod custody_mode {
+    use super::*;
+    use access_control::grant_role;
+    use deposit::deposit;
+    use init::init_default;
+    use reverse_swap::reverse_swap;
+    use swap::swap;
+
+    use crate::error::ERR_CUSTODY_MODE_UNSUPPORTED;
+    use crate::helpers::MsgMint;
+    use crate::msg::CustodyModeResponse;
+    use crate::state::CustodyMode;
+    use prost::Message;
+
+    const RELAYER: &str = "relayer";
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn set_mode(deps: DepsMut, caller: &str, mode: CustodyMode) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::SetCustodyMode { mode };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn init_minted(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+        init_default(deps).unwrap();
+        grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+        set_mode(deps.as_mut(), DEFAULT_OWNER, CustodyMode::MintBurn).unwrap();
+    }
+
+    fn decode_mint(msg: &CosmosMsg) -> MsgMint {
+        match msg {
+            CosmosMsg::Any(msg) => {
+                assert_eq!("/osmosis.tokenfactory.v1beta1.MsgMint", msg.type_url);
+                MsgMint::decode(msg.value.as_slice()).unwrap()
+            }
+            _ => panic!("unexpected message in handle response"),
+        }
+    }
+
+    fn supply(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> Uint128 {
+        ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap().supply
+    }
+
+    #[test]
+    fn success_set_custody_mode() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let query_mode = |deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>| {
+            let response: CustodyModeResponse =
+                from_json(query(deps.as_ref(), mock_env(), QueryMsg::CustodyMode {}).unwrap())
+                    .unwrap();
+            response.mode
+        };
+        assert_eq!(CustodyMode::Lock, query_mode(&deps));
+
+        let response = set_mode(deps.as_mut(), DEFAULT_OWNER, CustodyMode::MintBurn).unwrap();
+        assert_eq!(attr("action", "set_custody_mode"), response.attributes[0]);
+        assert_eq!(attr("mode", "mint_burn"), response.attributes[1]);
+        assert_eq!(CustodyMode::MintBurn, query_mode(&deps));
+    }
+
+    #[test]
+    fn failure_set_custody_mode() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = set_mode(deps.as_mut(), ACC1, CustodyMode::MintBurn);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        // locked tokens can't be turned into issued ones
+        deposit(&mut deps, 1000u128, DEFAULT_OWNER).unwrap();
+        let response = set_mode(deps.as_mut(), DEFAULT_OWNER, CustodyMode::MintBurn);
+        match response {
+            Err(ContractError::CustodyModeSupplyOutstanding { denom, supply }) => {
+                assert_eq!(DEFAULT_DENUM, denom);
+                assert_eq!(cu128!(1000u128), supply);
+            }
+            _ => panic!("expected error"),
+        }
+    }
+
+    #[test]
+    fn success_swap_burns() {
+        let mut deps = mock_deps();
+        init_minted(&mut deps);
+
+        // nothing issued yet
+        let response = swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128);
+        expect_error!(response, ERR_SUPPLY_EXCEEDED);
+
+        reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        assert_eq!(cu128!(800u128), supply(&deps));
+
+        let response = swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+        assert_eq!(1, response.messages.len());
+        match &response.messages[0].msg {
+            CosmosMsg::Any(msg) => {
+                assert_eq!("/osmosis.tokenfactory.v1beta1.MsgBurn", msg.type_url);
+            }
+            _ => panic!("unexpected message in handle response"),
+        }
+        assert_eq!(cu128!(300u128), supply(&deps));
+    }
+
+    #[test]
+    fn failure_change_denom_admin_supply_outstanding() {
+        let mut deps = mock_deps();
+        init_minted(&mut deps);
+        reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+
+        let msg = ExecuteMsg::ChangeDenomAdmin {
+            new_admin: addr!(ACC2),
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg.clone(),
+        );
+        match response {
+            Err(ContractError::DenomAdminSupplyOutstanding { denom, supply }) => {
+                assert_eq!(DEFAULT_DENUM, denom);
+                assert_eq!(cu128!(800u128), supply);
+            }
+            _ => panic!("expected error"),
+        }
+
+        // free to go once nothing issued is outstanding
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 800u128).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn success_payouts_mint() {
+        let mut deps = mock_deps();
+        init_minted(&mut deps);
+
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC2, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        let mint = decode_mint(&response.messages[0].msg);
+        assert_eq!(ACC2, mint.mint_to_address);
+        assert_eq!(
+            "700",
+            mint.amount.unwrap().amount,
+            "fee is issued but kept by the contract"
+        );
+        assert_eq!(cu128!(800u128), supply(&deps));
+
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+        let msg = ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC1),
+            amount: cu128!(500u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        let mint = decode_mint(&response.messages[0].msg);
+        assert_eq!(ACC1, mint.mint_to_address);
+        assert_eq!("400", mint.amount.unwrap().amount);
+        assert_eq!(cu128!(800u128), supply(&deps));
+
+        let msg = ExecuteMsg::WithdrawFees {
+            amount: cu128!(200u128),
+            destination: addr!(DEFAULT_OWNER),
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        let mint = decode_mint(&response.messages[0].msg);
+        assert_eq!(DEFAULT_OWNER, mint.mint_to_address);
+    }
+
+    #[test]
+    fn failure_deposit_and_withdraw() {
+        let mut deps = mock_deps();
+        init_minted(&mut deps);
+
+        let response = deposit(&mut deps, 1000u128, DEFAULT_OWNER);
+        expect_error!(response, ERR_CUSTODY_MODE_UNSUPPORTED);
+
+        let msg = ExecuteMsg::Withdraw {
+            amount: cu128!(1u128),
+            destination: addr!(DEFAULT_OWNER),
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        );
+        expect_error!(response, ERR_CUSTODY_MODE_UNSUPPORTED);
+    }
+}
+
+mod tokenfactory_dialect {
+    use super::*;
+    use init::init_default;
+
+    use crate::helpers::{MsgBurn, MsgMint, burn_tokens_from_contract, mint_tokens_to_address};
+    use crate::migrate::migrate;
+    use crate::msg::{MigrateMsg, TokenfactoryDialectResponse};
+    use crate::state::TokenfactoryDialect;
+    use cosmwasm_std::AnyMsg;
+    use prost::Message;
+
+    const DIALECTS: [(TokenfactoryDialect, &str); 2] = [
+        (
+            TokenfactoryDialect::Osmosis,
+            "/osmosis.tokenfactory.v1beta1",
+        ),
+        (
+            TokenfactoryDialect::Injective,
+            "/injective.tokenfactory.v1beta1",
+        ),
+    ];
+
+    fn any_msg(msg: CosmosMsg) -> AnyMsg {
+        match msg {
+            CosmosMsg::Any(msg) => msg,
+            _ => panic!("unexpected message in handle response"),
+        }
+    }
+
+    // decodes `msg` as `M` and checks it encodes back to the same bytes
+    fn round_trip<M: Message + Default>(msg: &AnyMsg) -> M {
+        let decoded = M::decode(msg.value.as_slice()).unwrap();
+        assert_eq!(msg.value.as_slice(), decoded.encode_to_vec().as_slice());
+        decoded
+    }
+
+    // hand-encoded from the field numbers of `MsgMint` in `tx.proto` of
+    // `osmosis/tokenfactory/v1beta1` (`mint_to_address = 3`) and
+    // `injective/tokenfactory/v1beta1` (`receiver = 3`), which a round trip can't catch:
+    // `{sender: "contract", amount: {denom: "uatom", amount: "972"}, 3: "recipient"}`
+    const MINT_PROTO_BYTES: &[u8] =
+        b"\x0a\x08contract\x12\x0c\x0a\x05uatom\x12\x03972\x1a\x09recipient";
+
+    // `MsgBurn{sender: "contract", amount: {denom: "uatom", amount: "400"}}` alike, the empty
+    // `burn_from_address = 3` is not encoded
+    const BURN_PROTO_BYTES: &[u8] = b"\x0a\x08contract\x12\x0c\x0a\x05uatom\x12\x03400";
+
+    fn contract_env() -> Env {
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked("contract");
+        env
+    }
+
+    fn dialect(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> TokenfactoryDialect {
+        let response: TokenfactoryDialectResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::TokenfactoryDialect {}).unwrap())
+                .unwrap();
+        response.dialect
+    }
+
+    #[test]
+    fn success_mint_round_trip() {
+        let env = mock_env();
+        for (dialect, package) in DIALECTS {
+            let msg = any_msg(
+                mint_tokens_to_address(
+                    &env,
+                    dialect,
+                    DEFAULT_DENUM.to_string(),
+                    cu128!(972u128),
+                    ACC1.to_string(),
+                )
+                .unwrap(),
+            );
+            assert_eq!(format!("{package}.MsgMint"), msg.type_url);
+
+            let mint: MsgMint = round_trip(&msg);
+            assert_eq!(env.contract.address.as_str(), mint.sender);
+            assert_eq!(DEFAULT_DENUM, mint.amount.as_ref().unwrap().denom);
+            assert_eq!("972", mint.amount.unwrap().amount);
+            assert_eq!(ACC1, mint.mint_to_address);
+        }
+    }
+
+    #[test]
+    fn success_burn_round_trip() {
+        let env = mock_env();
+        for (dialect, package) in DIALECTS {
+            let msg = any_msg(
+                burn_tokens_from_contract(
+                    &env,
+                    dialect,
+                    DEFAULT_DENUM.to_string(),
+                    cu128!(400u128),
+                )
+                .unwrap(),
+            );
+            assert_eq!(format!("{package}.MsgBurn"), msg.type_url);
+
+            let burn: MsgBurn = round_trip(&msg);
+            assert_eq!(env.contract.address.as_str(), burn.sender);
+            assert_eq!(DEFAULT_DENUM, burn.amount.as_ref().unwrap().denom);
+            assert_eq!("400", burn.amount.unwrap().amount);
+            assert_eq!("", burn.burn_from_address);
+        }
+    }
+
+    #[test]
+    fn success_mint_proto_field_numbers() {
+        let env = contract_env();
+        for (dialect, _) in DIALECTS {
+            let msg = any_msg(
+                mint_tokens_to_address(
+                    &env,
+                    dialect,
+                    "uatom".to_string(),
+                    cu128!(972u128),
+                    "recipient".to_string(),
+                )
+                .unwrap(),
+            );
+            assert_eq!(MINT_PROTO_BYTES, msg.value.as_slice());
+        }
+    }
+
+    #[test]
+    fn success_burn_proto_field_numbers() {
+        let env = contract_env();
+        for (dialect, _) in DIALECTS {
+            let msg = any_msg(
+                burn_tokens_from_contract(&env, dialect, "uatom".to_string(), cu128!(400u128))
+                    .unwrap(),
+            );
+            assert_eq!(BURN_PROTO_BYTES, msg.value.as_slice());
+        }
+    }
+
+    #[test]
+    fn success_dialect_at_instantiate() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        assert_eq!(TokenfactoryDialect::Osmosis, dialect(&deps));
+
+        let mut deps = mock_deps();
+        let msg = InstantiateMsg {
+            cap: cu128!(DEFAULT_CAP),
+            reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE),
+            reverse_aggregated_allowance_approver_cap: cu128!(DEFAULT_RA_ALLOWANCE_APPROVER_CAP),
+            upper_swap_limit: cu128!(DEFAULT_SWAP_UPPER_LIMIT),
+            lower_swap_limit: cu128!(DEFAULT_SWAP_LOWER_LIMIT),
+            swap_fee: cu128!(DEFAULT_SWAP_FEE),
+            paused_since_block: None,
+            denom: Some(DEFAULT_DENUM.to_string()),
+            next_swap_id: 0,
+            tokenfactory_dialect: Some(TokenfactoryDialect::Injective),
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(TokenfactoryDialect::Injective, dialect(&deps));
+
+        let msg = ExecuteMsg::Mint {
+            amount: cu128!(972u128),
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        let msg = any_msg(response.messages[0].msg.clone());
+        assert_eq!("/injective.tokenfactory.v1beta1.MsgMint", msg.type_url);
+    }
+
+    #[test]
+    fn success_dialect_at_migrate() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let msg = MigrateMsg {
+            forced: Some(true),
+            instantiate_msg: None,
+            tokenfactory_dialect: Some(TokenfactoryDialect::Injective),
+        };
+        migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(TokenfactoryDialect::Injective, dialect(&deps));
+
+        // kept when not given
+        let msg = MigrateMsg {
+            forced: Some(true),
+            instantiate_msg: None,
+            tokenfactory_dialect: None,
+        };
+        migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(TokenfactoryDialect::Injective, dialect(&deps));
+
+        let msg = ExecuteMsg::Burn {
+            amount: cu128!(0u128),
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        let msg = any_msg(response.messages[0].msg.clone());
+        assert_eq!("/injective.tokenfactory.v1beta1.MsgBurn", msg.type_url);
+    }
+}
+
+mod tokenfactory_denom {
+    use super::*;
+    use deposit::deposit;
+    use init::init_default;
+
+    use crate::error::{
+        ERR_DENOM_BALANCES_OUTSTANDING, ERR_DENOM_SUPPLY_OUTSTANDING, ERR_UNKNOWN_ASSET,
+    };
+    use crate::helpers::{MsgChangeAdmin, MsgCreateDenom, MsgSetDenomMetadata};
+    use crate::migrate::migrate;
+    use crate::msg::{DenomResponse, MigrateMsg};
+    use crate::state::{
+        CLAIMABLE, OUTFLOW_LIMIT, OUTFLOW_USAGE, OutflowLimit, RateWindow, SENDER_LIMITS,
+        SENDER_USAGE, SenderLimits,
+    };
+    use access_control::grant_role;
+    use swap::swap;
+
+    const RELAYER: &str = "relayer";
+    const NEW_DENOM: &str = "anewdenom";
+
+    fn refund(deps: DepsMut, id: u64) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Refund {
+            id,
+            to: addr!(ACC1),
+            amount: cu128!(500u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        exec(deps, RELAYER, msg)
+    }
+    use cosmwasm_std::{DenomMetadata, DenomUnit};
+    use prost::Message;
+
+    fn exec(deps: DepsMut, caller: &str, msg: ExecuteMsg) -> Result<Response, ContractError> {
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn create_denom(deps: DepsMut, caller: &str) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::CreateDenom {
+            subdenom: "fet".to_string(),
+        };
+        exec(deps, caller, msg)
+    }
+
+    fn factory_denom() -> String {
+        format!("factory/{}/fet", mock_env().contract.address)
+    }
+
+    fn decode<M: Message + Default>(msg: &CosmosMsg, type_url: &str) -> M {
+        match msg {
+            CosmosMsg::Any(msg) => {
+                assert_eq!(type_url, msg.type_url);
+                M::decode(msg.value.as_slice()).unwrap()
+            }
+            _ => panic!("unexpected message in handle response"),
+        }
+    }
+
+    fn metadata(base: &str) -> DenomMetadata {
+        DenomMetadata {
+            description: "bridged FET".to_string(),
+            denom_units: vec![
+                DenomUnit {
+                    denom: base.to_string(),
+                    exponent: 0,
+                    aliases: vec!["afet".to_string()],
+                },
+                DenomUnit {
+                    denom: "fet".to_string(),
+                    exponent: 18,
+                    aliases: vec![],
+                },
+            ],
+            base: base.to_string(),
+            display: "fet".to_string(),
+            name: "Fetch.ai".to_string(),
+            symbol: "FET".to_string(),
+            uri: "".to_string(),
+            uri_hash: "".to_string(),
+        }
+    }
+
+    #[test]
+    fn success_create_denom() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        let limit = OutflowLimit {
+            max_outflow: cu128!(300u128),
+            window: RateWindow::Blocks(100),
+        };
+        let msg = ExecuteMsg::SetOutflowLimit {
+            limit: Some(limit.clone()),
+            asset: None,
+        };
+        exec(deps.as_mut(), DEFAULT_OWNER, msg).unwrap();
+
+        let response = create_denom(deps.as_mut(), DEFAULT_OWNER).unwrap();
+        let create: MsgCreateDenom = decode(
+            &response.messages[0].msg,
+            "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+        );
+        assert_eq!(mock_env().contract.address.as_str(), create.sender);
+        assert_eq!("fet", create.subdenom);
+        assert_eq!(attr("action", "create_denom"), response.attributes[0]);
+        assert_eq!(attr("denom", factory_denom()), response.attributes[2]);
+
+        // the default asset moved to the new denom
+        let response: DenomResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::Denom {}).unwrap()).unwrap();
+        assert_eq!(factory_denom(), response.denom);
+        assert!(!ASSETS.has(&deps.storage, DEFAULT_DENUM));
+        let asset = ASSETS.load(&deps.storage, &factory_denom()).unwrap();
+        assert_eq!(cu128!(DEFAULT_CAP), asset.cap);
+        assert_eq!(
+            limit,
+            OUTFLOW_LIMIT.load(&deps.storage, &factory_denom()).unwrap()
+        );
+    }
+
+    #[test]
+    fn failure_create_denom() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = create_denom(deps.as_mut(), ACC1);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        deposit(&mut deps, 1000u128, DEFAULT_OWNER).unwrap();
+        let response = create_denom(deps.as_mut(), DEFAULT_OWNER);
+        expect_error!(response, ERR_DENOM_SUPPLY_OUTSTANDING);
+    }
+
+    #[test]
+    fn failure_create_denom_balances_outstanding() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        CLAIMABLE
+            .save(
+                &mut deps.storage,
+                (&addr!(ACC1), DEFAULT_DENUM),
+                &cu128!(10u128),
+            )
+            .unwrap();
+        let response = create_denom(deps.as_mut(), DEFAULT_OWNER);
+        expect_error!(response, ERR_DENOM_BALANCES_OUTSTANDING);
+    }
+
+    #[test]
+    fn success_migrate_denom_moves_usage() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        grant_role(&mut deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+        let limits = SenderLimits {
+            window: RateWindow::Blocks(100),
+            max_volume: Some(cu128!(5000u128)),
+            max_swaps: None,
+        };
+        let msg = ExecuteMsg::SetSenderLimits {
+            limits: Some(limits.clone()),
+            asset: None,
+        };
+        exec(deps.as_mut(), DEFAULT_OWNER, msg).unwrap();
+        let limit = OutflowLimit {
+            max_outflow: cu128!(3000u128),
+            window: RateWindow::Blocks(100),
+        };
+        let msg = ExecuteMsg::SetOutflowLimit {
+            limit: Some(limit.clone()),
+            asset: None,
+        };
+        exec(deps.as_mut(), DEFAULT_OWNER, msg).unwrap();
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+        refund(deps.as_mut(), 0).unwrap();
+
+        let msg = MigrateMsg {
+            forced: Some(true),
+            instantiate_msg: Some(InstantiateMsg {
+                cap: cu128!(DEFAULT_CAP),
+                reverse_aggregated_allowance: cu128!(DEFAULT_RA_ALLOWANCE),
+                reverse_aggregated_allowance_approver_cap: cu128!(
+                    DEFAULT_RA_ALLOWANCE_APPROVER_CAP
+                ),
+                upper_swap_limit: cu128!(DEFAULT_SWAP_UPPER_LIMIT),
+                lower_swap_limit: cu128!(DEFAULT_SWAP_LOWER_LIMIT),
+                swap_fee: cu128!(DEFAULT_SWAP_FEE),
+                paused_since_block: None,
+                denom: Some(NEW_DENOM.to_string()),
+                next_swap_id: 0,
+                tokenfactory_dialect: None,
+            }),
+            tokenfactory_dialect: None,
+        };
+        migrate(deps.as_mut(), mock_env(), msg).unwrap();
+
+        assert_eq!(
+            limits,
+            SENDER_LIMITS.load(&deps.storage, NEW_DENOM).unwrap()
+        );
+        assert_eq!(limit, OUTFLOW_LIMIT.load(&deps.storage, NEW_DENOM).unwrap());
+        let sender = (NEW_DENOM, &addr!(ACC1));
+        assert_eq!(
+            cu128!(1000u128),
+            SENDER_USAGE.load(&deps.storage, sender).unwrap().volume
+        );
+        assert!(!SENDER_USAGE.has(&deps.storage, (DEFAULT_DENUM, &addr!(ACC1))));
+        assert_eq!(
+            cu128!(2500u128),
+            OUTFLOW_USAGE
+                .load(&deps.storage, NEW_DENOM)
+                .unwrap()
+                .available
+        );
+        assert!(!OUTFLOW_USAGE.has(&deps.storage, DEFAULT_DENUM));
+
+        // the swaps made before are refunded in the new denom
+        let response = refund(deps.as_mut(), 1).unwrap();
+        assert_eq!(
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ACC1.to_string(),
+                amount: coins(500u128 - DEFAULT_SWAP_FEE, NEW_DENOM),
+            }),
+            response.messages[0].msg
+        );
+    }
+
+    #[test]
+    fn success_change_denom_admin() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        create_denom(deps.as_mut(), DEFAULT_OWNER).unwrap();
+
+        let msg = ExecuteMsg::ChangeDenomAdmin {
+            new_admin: addr!(ACC1),
+            asset: None,
+        };
+        let response = exec(deps.as_mut(), DEFAULT_OWNER, msg.clone()).unwrap();
+        let change: MsgChangeAdmin = decode(
+            &response.messages[0].msg,
+            "/osmosis.tokenfactory.v1beta1.MsgChangeAdmin",
+        );
+        assert_eq!(factory_denom(), change.denom);
+        assert_eq!(ACC1, change.new_admin);
+
+        let response = exec(deps.as_mut(), ACC1, msg);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+    }
+
+    #[test]
+    fn success_denom_changes_timelocked() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        let height = mock_env().block.height;
+        let msg = ExecuteMsg::SetTimelockDelay { blocks: 10 };
+        exec_at(&mut deps, DEFAULT_OWNER, height, msg).unwrap();
+
+        let response = create_denom(deps.as_mut(), DEFAULT_OWNER).unwrap();
+        assert!(response.messages.is_empty());
+        assert!(ASSETS.has(&deps.storage, DEFAULT_DENUM));
+        let msg = ExecuteMsg::ChangeDenomAdmin {
+            new_admin: addr!(ACC1),
+            asset: None,
+        };
+        let response = exec(deps.as_mut(), DEFAULT_OWNER, msg).unwrap();
+        assert!(response.messages.is_empty());
+
+        let msg = ExecuteMsg::ExecuteQueued { id: 0 };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + 10, msg).unwrap();
+        assert_eq!(1, response.messages.len());
+        assert!(ASSETS.has(&deps.storage, &factory_denom()));
+        let msg = ExecuteMsg::ExecuteQueued { id: 1 };
+        let response = exec_at(&mut deps, DEFAULT_OWNER, height + 10, msg).unwrap();
+        let change: MsgChangeAdmin = decode(
+            &response.messages[0].msg,
+            "/osmosis.tokenfactory.v1beta1.MsgChangeAdmin",
+        );
+        assert_eq!(factory_denom(), change.denom);
+    }
+
+    #[test]
+    fn success_set_denom_metadata() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+        create_denom(deps.as_mut(), DEFAULT_OWNER).unwrap();
+
+        let msg = ExecuteMsg::SetDenomMetadata {
+            metadata: metadata(&factory_denom()),
+        };
+        let response = exec(deps.as_mut(), DEFAULT_OWNER, msg).unwrap();
+        let set: MsgSetDenomMetadata = decode(
+            &response.messages[0].msg,
+            "/osmosis.tokenfactory.v1beta1.MsgSetDenomMetadata",
+        );
+        let metadata = set.metadata.unwrap();
+        assert_eq!(factory_denom(), metadata.base);
+        assert_eq!("FET", metadata.symbol);
+        assert_eq!(2, metadata.denom_units.len());
+        assert_eq!(18, metadata.denom_units[1].exponent);
+        assert_eq!(vec!["afet".to_string()], metadata.denom_units[0].aliases);
+
+        // only for bridged assets
+        let msg = ExecuteMsg::SetDenomMetadata {
+            metadata: self::metadata("factory/other/fet"),
+        };
+        let response = exec(deps.as_mut(), DEFAULT_OWNER, msg);
+        expect_error!(response, ERR_UNKNOWN_ASSET);
+    }
+}
+
+mod failed_payouts {
+    use super::*;
+    use reverse_swap::reverse_swap;
+
+    use crate::contract::{PAYOUT_REPLY_ID, reply};
+    use crate::error::ERR_NOTHING_TO_CLAIM;
+    use crate::msg::{ClaimableResponse, ReverseSwapProcessedResponse};
+    use crate::state::Payout;
+    use cosmwasm_std::{Coin, Reply, ReplyOn, SubMsg, SubMsgResult, coin};
+
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    // replies to `submsg` as if its execution failed
+    fn fail(deps: DepsMut, submsg: &SubMsg) -> Response {
+        assert_eq!(ReplyOn::Error, submsg.reply_on);
+        let msg = Reply {
+            id: submsg.id,
+            payload: submsg.payload.clone(),
+            gas_used: 0,
+            result: SubMsgResult::Err("blocked address".to_string()),
+        };
+        reply(deps, mock_env(), msg).unwrap()
+    }
+
+    fn claimable(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, address: &str) -> Vec<Coin> {
+        let msg = QueryMsg::Claimable {
+            address: addr!(address),
+        };
+        let response: ClaimableResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        response.claimable
+    }
+
+    fn redirect(deps: DepsMut, caller: &str, address: &str) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::RedirectClaimable {
+            address: addr!(address),
+            to: addr!(ACC2),
+            asset: None,
+        };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    #[test]
+    fn success_failed_reverse_swap_payout_credited() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        let submsg = &response.messages[0];
+        assert_eq!(PAYOUT_REPLY_ID, submsg.id);
+        let payout: Payout = from_json(&submsg.payload).unwrap();
+        assert_eq!(addr!(ACC1), payout.to);
+        assert_eq!(cu128!(700u128), payout.amount);
+
+        let response = fail(deps.as_mut(), submsg);
+        let event = &response.events[0];
+        assert_eq!("payout_failed", event.ty);
+        assert!(
+            event
+                .attributes
+                .contains(&attr("operation", "reverse_swap"))
+        );
+        assert!(event.attributes.contains(&attr("error", "blocked address")));
+
+        assert_eq!(vec![coin(700u128, DEFAULT_DENUM)], claimable(&deps, ACC1));
+        let msg = QueryMsg::ReverseSwapProcessed { rid: 0 };
+        let processed: ReverseSwapProcessedResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert!(processed.processed);
+
+        // the recipient claims it later on
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(ACC1), &[]),
+            ExecuteMsg::Claim {},
+        )
+        .unwrap();
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ACC1.to_string(),
+                amount: coins(700u128, DEFAULT_DENUM),
+            })
+        );
+        assert!(claimable(&deps, ACC1).is_empty());
+    }
+
+    #[test]
+    fn success_redirect_failed_refund_payout() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        swap::swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+
+        let msg = ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC1),
+            amount: cu128!(500u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        fail(deps.as_mut(), &response.messages[0]);
+        assert_eq!(vec![coin(400u128, DEFAULT_DENUM)], claimable(&deps, ACC1));
+
+        let response = redirect(deps.as_mut(), ACC2, ACC1);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        let response = redirect(deps.as_mut(), DEFAULT_OWNER, ACC1).unwrap();
+        assert_eq!(ReplyOn::Never, response.messages[0].reply_on);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ACC2.to_string(),
+                amount: coins(400u128, DEFAULT_DENUM),
+            })
+        );
+        assert!(claimable(&deps, ACC1).is_empty());
+
+        let response = redirect(deps.as_mut(), ACC1, ACC1);
+        expect_error!(response, ERR_NOTHING_TO_CLAIM);
+    }
+
+    #[test]
+    fn success_admin_redirect_timelocked() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        let msg = ExecuteMsg::SetTimelockDelay { blocks: 10 };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        fail(deps.as_mut(), &response.messages[0]);
+
+        let response = redirect(deps.as_mut(), DEFAULT_OWNER, ACC1).unwrap();
+        assert_eq!(attr("action", "queue_operation"), response.attributes[0]);
+        assert!(response.messages.is_empty());
+        assert_eq!(vec![coin(700u128, DEFAULT_DENUM)], claimable(&deps, ACC1));
+
+        // the recipient itself isn't held back
+        let response = redirect(deps.as_mut(), ACC1, ACC1).unwrap();
+        assert_eq!(attr("action", "redirect_claimable"), response.attributes[0]);
+        assert!(claimable(&deps, ACC1).is_empty());
+    }
+
+    #[test]
+    fn success_withdraw_not_recovered() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let msg = ExecuteMsg::Withdraw {
+            amount: cu128!(1000u128),
+            destination: addr!(DEFAULT_OWNER),
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(ReplyOn::Never, response.messages[0].reply_on);
+    }
+}
+
+mod payout_mode {
+    use super::*;
+    use access_control::grant_role;
+    use deposit::deposit;
+    use init::init_default;
+    use reverse_swap::reverse_swap;
+    use swap::swap;
+
+    use crate::msg::{ClaimableBalance, ListClaimableResponse, PayoutModeResponse};
+    use crate::state::PayoutMode;
+
+    const RELAYER: &str = "relayer";
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn set_mode(deps: DepsMut, caller: &str, mode: PayoutMode) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::SetPayoutMode { mode };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn init_pull(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+        init_default(deps).unwrap();
+        grant_role(deps, RELAYER_ROLE, RELAYER, DEFAULT_OWNER).unwrap();
+        deposit(deps, 10000u128, DEFAULT_OWNER).unwrap();
+        set_mode(deps.as_mut(), DEFAULT_OWNER, PayoutMode::Pull).unwrap();
+    }
+
+    fn list_claimable(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        start_after: Option<(Addr, String)>,
+        limit: Option<u32>,
+    ) -> Vec<ClaimableBalance> {
+        let msg = QueryMsg::ListClaimable { start_after, limit };
+        let response: ListClaimableResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        response.claimable
+    }
+
+    fn balance(address: &str, amount: u128) -> ClaimableBalance {
+        ClaimableBalance {
+            address: addr!(address),
+            denom: DEFAULT_DENUM.to_string(),
+            amount: cu128!(amount),
+        }
+    }
+
+    #[test]
+    fn success_set_payout_mode() {
+        let mut deps = mock_deps();
+        init_default(&mut deps).unwrap();
+
+        let response = set_mode(deps.as_mut(), ACC1, PayoutMode::Pull);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        let response = set_mode(deps.as_mut(), DEFAULT_OWNER, PayoutMode::Pull).unwrap();
+        assert_eq!(attr("mode", "pull"), response.attributes[1]);
+        let response: PayoutModeResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::PayoutMode {}).unwrap()).unwrap();
+        assert_eq!(PayoutMode::Pull, response.mode);
+    }
+
+    #[test]
+    fn success_pull_payouts_credited() {
+        let mut deps = mock_deps();
+        init_pull(&mut deps);
+
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        assert!(response.messages.is_empty());
+        assert_eq!("payout_credited", response.events[0].ty);
+
+        swap(deps.as_mut(), ACC2, ETH_ACC1, 500u128).unwrap();
+        let msg = ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC2),
+            amount: cu128!(500u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert!(response.messages.is_empty());
+
+        // accounting is that of a delivered payout
+        let asset = ASSETS.load(&deps.storage, DEFAULT_DENUM).unwrap();
+        assert_eq!(cu128!(10000u128 - 800 + 500 - 500), asset.supply);
+        assert_eq!(cu128!(200u128), asset.fees_accrued);
+
+        let mut expected = vec![balance(ACC1, 700), balance(ACC2, 400)];
+        expected.sort_by(|a, b| a.address.cmp(&b.address));
+        assert_eq!(expected, list_claimable(&deps, None, None));
+        let first = &expected[0];
+        assert_eq!(
+            expected[1..].to_vec(),
+            list_claimable(
+                &deps,
+                Some((first.address.clone(), first.denom.clone())),
+                None
+            )
+        );
+        assert_eq!(expected[..1].to_vec(), list_claimable(&deps, None, Some(1)));
+    }
+
+    #[test]
+    fn success_claim_for() {
+        let mut deps = mock_deps();
+        init_pull(&mut deps);
+        reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+
+        // anyone can deliver the payout, always to its recipient
+        let msg = ExecuteMsg::ClaimFor {
+            address: addr!(ACC1),
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ACC1.to_string(),
+                amount: coins(700u128, DEFAULT_DENUM),
+            })
+        );
+        assert!(list_claimable(&deps, None, None).is_empty());
+    }
+}
+
+mod operation_pause {
+    use super::*;
+    use deposit::deposit;
+    use pause::{pause_public_api, pause_relayer_api};
+    use reverse_swap::reverse_swap;
+    use swap::swap;
+
+    use crate::msg::{OperationPauseStatus, PauseStatusResponse};
+    use crate::state::{PAUSES, PausableOperation, PauseStart};
+
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn pause(
+        deps: DepsMut,
+        caller: &str,
+        operation: PausableOperation,
+        since_block: u64,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Pause {
+            operation,
+            since: PauseStart::Height(since_block),
+            reason: None,
+        };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn refund(deps: DepsMut, id: u64) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Refund {
+            id,
+            to: addr!(ACC1),
+            amount: cu128!(500u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        execute(deps, mock_env(), message_info(&addr!(RELAYER), &[]), msg)
+    }
+
+    fn withdraw(deps: DepsMut) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Withdraw {
+            amount: cu128!(100u128),
+            destination: addr!(DEFAULT_OWNER),
+            asset: None,
+        };
+        execute(
+            deps,
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+    }
+
+    fn pause_status(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> PauseStatusResponse {
+        from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PauseStatus { asset: None },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn operation_status(
+        status: &PauseStatusResponse,
+        operation: PausableOperation,
+    ) -> OperationPauseStatus {
+        status
+            .operations
+            .iter()
+            .find(|status| status.operation == operation)
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn success_pause_refund_only() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+
+        let response = pause(deps.as_mut(), MONITOR, PausableOperation::Refund, 0).unwrap();
+        assert_eq!(attr("action", "pause"), response.attributes[0]);
+        assert_eq!(attr("operation", "refund"), response.attributes[1]);
+
+        expect_error!(refund(deps.as_mut(), 0), ERR_CONTRACT_PAUSED);
+        // the rest of the relayer API keeps running
+        reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+
+        let status = pause_status(&deps);
+        assert!(!status.relayer_api.paused);
+        let refund_status = operation_status(&status, PausableOperation::Refund);
+        assert!(refund_status.paused);
+        assert_eq!(
+            PauseStart::Height(mock_env().block.height),
+            refund_status.pause.unwrap().since
+        );
+        assert!(!operation_status(&status, PausableOperation::ReverseSwap).paused);
+
+        // only an admin unpauses
+        let response = pause(deps.as_mut(), MONITOR, PausableOperation::Refund, u64::MAX);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        pause(
+            deps.as_mut(),
+            DEFAULT_OWNER,
+            PausableOperation::Refund,
+            u64::MAX,
+        )
+        .unwrap();
+        refund(deps.as_mut(), 0).unwrap();
+    }
+
+    #[test]
+    fn success_pause_admin_funds() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let response = pause(deps.as_mut(), ACC1, PausableOperation::Withdraw, 0);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        pause(deps.as_mut(), MONITOR, PausableOperation::Withdraw, 0).unwrap();
+        expect_error!(withdraw(deps.as_mut()), ERR_CONTRACT_PAUSED);
+        deposit(&mut deps, 100u128, DEFAULT_OWNER).unwrap();
+
+        pause(deps.as_mut(), MONITOR, PausableOperation::Deposit, 0).unwrap();
+        expect_error!(
+            deposit(&mut deps, 100u128, DEFAULT_OWNER),
+            ERR_CONTRACT_PAUSED
+        );
+
+        pause(deps.as_mut(), MONITOR, PausableOperation::MintBurn, 0).unwrap();
+        let msg = ExecuteMsg::Mint {
+            amount: cu128!(100u128),
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        );
+        expect_error!(response, ERR_CONTRACT_PAUSED);
+    }
+
+    #[test]
+    fn success_pause_status_reports_api_pauses() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let status = pause_status(&deps);
+        assert_eq!(PausableOperation::ALL.len(), status.operations.len());
+        assert!(status.operations.iter().all(|status| !status.paused));
+
+        pause_public_api(&mut deps, message_info(&addr!(MONITOR), &[])).unwrap();
+        let status = pause_status(&deps);
+        assert!(status.public_api.paused);
+        let swap_status = operation_status(&status, PausableOperation::Swap);
+        assert!(swap_status.paused);
+        assert_eq!(addr!(MONITOR), swap_status.pause.unwrap().paused_by);
+        assert!(!operation_status(&status, PausableOperation::Refund).paused);
+    }
+
+    #[test]
+    fn success_api_pause_is_pause_of_its_operations() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+
+        pause_relayer_api(&mut deps, message_info(&addr!(MONITOR), &[])).unwrap();
+        assert!(PAUSES.has(&deps.storage, "reverse_swap"));
+        assert!(PAUSES.has(&deps.storage, "refund"));
+        assert!(pause_status(&deps).relayer_api.paused);
+
+        // lifting the pause of one operation of the API lifts it for that one only
+        pause(
+            deps.as_mut(),
+            DEFAULT_OWNER,
+            PausableOperation::ReverseSwap,
+            u64::MAX,
+        )
+        .unwrap();
+        assert!(!pause_status(&deps).relayer_api.paused);
+        reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        expect_error!(refund(deps.as_mut(), 0), ERR_CONTRACT_PAUSED);
+    }
+
+    #[test]
+    fn success_pause_status_reports_asset_pauses() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let msg = ExecuteMsg::PausePublicApi {
+            since_block: 0,
+            asset: Some(DEFAULT_DENUM.to_string()),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(MONITOR), &[]),
+            msg,
+        )
+        .unwrap();
+        assert!(!operation_status(&pause_status(&deps), PausableOperation::Swap).paused);
+
+        let msg = QueryMsg::PauseStatus {
+            asset: Some(DEFAULT_DENUM.to_string()),
+        };
+        let status: PauseStatusResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let asset = status.asset.as_ref().unwrap();
+        assert!(asset.public_api.paused);
+        assert!(!asset.relayer_api.paused);
+        assert!(!status.public_api.paused);
+        assert!(operation_status(&status, PausableOperation::Swap).paused);
+        assert!(!operation_status(&status, PausableOperation::Refund).paused);
+    }
+}
+
+mod pause_expiry {
+    use super::*;
+    use swap::swap;
+
+    use crate::error::ERR_PAUSE_NOT_EXPIRING;
+    use crate::msg::PauseStatusResponse;
+    use crate::state::{OperationPause, PausableOperation, PauseStart};
+
+    const REASON: &str = "suspicious outflows";
+
+    fn env_after(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+
+    fn pause_swap(
+        deps: DepsMut,
+        caller: &str,
+        since: PauseStart,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Pause {
+            operation: PausableOperation::Swap,
+            since,
+            reason: Some(REASON.to_string()),
+        };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn swap_at(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Swap {
+            destination: ETH_ACC1.to_string(),
+            asset: None,
+        };
+        execute(
+            deps,
+            env,
+            message_info(&addr!(ACC1), &coins(500u128, DEFAULT_DENUM)),
+            msg,
+        )
+    }
+
+    fn swap_pause(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: Env,
+    ) -> (bool, Option<OperationPause>) {
+        let status: PauseStatusResponse =
+            from_json(query(deps.as_ref(), env, QueryMsg::PauseStatus { asset: None }).unwrap())
+                .unwrap();
+        let swap_status = status
+            .operations
+            .into_iter()
+            .find(|status| status.operation == PausableOperation::Swap)
+            .unwrap();
+        (swap_status.paused, swap_status.pause)
+    }
+
+    fn set_monitor_pause_max(
+        deps: DepsMut,
+        caller: &str,
+        seconds: Option<u64>,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::SetMonitorPauseMax { seconds };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn confirm_pause(deps: DepsMut, caller: &str) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::ConfirmPause {
+            operation: PausableOperation::Swap,
+        };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    #[test]
+    fn success_pause_since_time() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let since = mock_env().block.time.plus_seconds(100);
+        pause_swap(deps.as_mut(), DEFAULT_OWNER, PauseStart::Time(since)).unwrap();
+
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+        let response = swap_at(deps.as_mut(), env_after(100));
+        assert!(response.as_ref().unwrap_err().to_string().contains(REASON));
+        expect_error!(response, ERR_CONTRACT_PAUSED);
+
+        let (paused, pause) = swap_pause(&deps, env_after(100));
+        assert!(paused);
+        let pause = pause.unwrap();
+        assert_eq!(PauseStart::Time(since), pause.since);
+        assert_eq!(REASON, pause.reason);
+        assert_eq!(None, pause.expires_at);
+    }
+
+    #[test]
+    fn success_monitor_pause_expires() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        let response = set_monitor_pause_max(deps.as_mut(), MONITOR, Some(600));
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        set_monitor_pause_max(deps.as_mut(), DEFAULT_OWNER, Some(600)).unwrap();
+
+        let response = pause_swap(deps.as_mut(), MONITOR, PauseStart::Height(0)).unwrap();
+        let expires_at = mock_env().block.time.plus_seconds(600);
+        assert!(
+            response
+                .attributes
+                .contains(&attr("expires_at", expires_at.seconds().to_string()))
+        );
+
+        expect_error!(swap_at(deps.as_mut(), env_after(599)), ERR_CONTRACT_PAUSED);
+        swap_at(deps.as_mut(), env_after(600)).unwrap();
+
+        // lapsed pauses stay visible
+        let (paused, pause) = swap_pause(&deps, env_after(600));
+        assert!(!paused);
+        assert_eq!(Some(expires_at), pause.unwrap().expires_at);
+    }
+
+    #[test]
+    fn success_confirm_monitor_pause() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        set_monitor_pause_max(deps.as_mut(), DEFAULT_OWNER, Some(600)).unwrap();
+
+        pause_swap(deps.as_mut(), MONITOR, PauseStart::Height(0)).unwrap();
+        expect_error!(
+            confirm_pause(deps.as_mut(), MONITOR),
+            ERR_ACCESS_CONTROL_ONLY_ADMIN
+        );
+        confirm_pause(deps.as_mut(), DEFAULT_OWNER).unwrap();
+        expect_error!(
+            confirm_pause(deps.as_mut(), DEFAULT_OWNER),
+            ERR_PAUSE_NOT_EXPIRING
+        );
+
+        expect_error!(swap_at(deps.as_mut(), env_after(600)), ERR_CONTRACT_PAUSED);
+        let (paused, pause) = swap_pause(&deps, env_after(600));
+        assert!(paused);
+        let pause = pause.unwrap();
+        assert_eq!(addr!(MONITOR), pause.paused_by);
+        assert_eq!(None, pause.expires_at);
+    }
+
+    #[test]
+    fn failure_monitor_replaces_lasting_pause() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        set_monitor_pause_max(deps.as_mut(), DEFAULT_OWNER, Some(600)).unwrap();
+
+        pause_swap(deps.as_mut(), DEFAULT_OWNER, PauseStart::Height(0)).unwrap();
+        let response = pause_swap(deps.as_mut(), MONITOR, PauseStart::Height(0));
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        expect_error!(swap_at(deps.as_mut(), env_after(600)), ERR_CONTRACT_PAUSED);
+
+        // nor a confirmed one, whichever way the pause starts
+        let unpause = PauseStart::Height(mock_env().block.height + 1);
+        pause_swap(deps.as_mut(), DEFAULT_OWNER, unpause).unwrap();
+        pause_swap(deps.as_mut(), MONITOR, PauseStart::Height(0)).unwrap();
+        confirm_pause(deps.as_mut(), DEFAULT_OWNER).unwrap();
+        let now = mock_env().block.time;
+        let response = pause_swap(deps.as_mut(), MONITOR, PauseStart::Time(now));
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+
+        expect_error!(swap_at(deps.as_mut(), env_after(600)), ERR_CONTRACT_PAUSED);
+        let (paused, pause) = swap_pause(&deps, env_after(600));
+        assert!(paused);
+        let pause = pause.unwrap();
+        assert_eq!(PauseStart::Height(mock_env().block.height), pause.since);
+        assert_eq!(None, pause.expires_at);
+    }
+}
+
+mod address_list {
+    use super::*;
+    use reverse_swap::reverse_swap;
+    use swap::swap;
+
+    use crate::contract::reply;
+    use crate::error::{ERR_ADDRESS_BLOCKED, ERR_HELD_PAYOUT_NOT_FOUND};
+    use crate::msg::{AddressListResponse, HeldPayoutsResponse};
+    use crate::state::{AddressList, CLAIMABLE, DustPolicy, Payout};
+    use cosmwasm_std::{Reply, SubMsgResult};
+
+    const HASH: &str = "HHHHHHAAAAAASSSHHH";
+
+    fn update_list(
+        deps: DepsMut,
+        caller: &str,
+        list: AddressList,
+        add: &[&str],
+        remove: &[&str],
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::UpdateAddressList {
+            list,
+            add: add.iter().map(|address| address.to_string()).collect(),
+            remove: remove.iter().map(|address| address.to_string()).collect(),
+        };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn release(
+        deps: DepsMut,
+        caller: &str,
+        operation: &str,
+        id: u64,
+        to: Option<&str>,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::ReleaseHeldPayout {
+            operation: operation.to_string(),
+            id,
+            to: to.map(|to| addr!(to)),
+        };
+        execute(deps, mock_env(), message_info(&addr!(caller), &[]), msg)
+    }
+
+    fn listed(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        list: AddressList,
+        start_after: Option<&str>,
+    ) -> AddressListResponse {
+        let msg = QueryMsg::AddressList {
+            list,
+            start_after: start_after.map(|address| address.to_string()),
+            limit: Some(1),
+        };
+        from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    }
+
+    fn held(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> Vec<Payout> {
+        let msg = QueryMsg::HeldPayouts {
+            start_after: None,
+            limit: None,
+        };
+        let response: HeldPayoutsResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        response.held
+    }
+
+    #[test]
+    fn success_block_swap_parties() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+
+        // listed checksummed, as swap destinations are
+        let eth_lowercase = ETH_ACC1.to_lowercase();
+        update_list(
+            deps.as_mut(),
+            MONITOR,
+            AddressList::Blocked,
+            &[ACC1, &eth_lowercase],
+            &[],
+        )
+        .unwrap();
+        expect_error!(
+            swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128),
+            ERR_ADDRESS_BLOCKED
+        );
+        expect_error!(
+            swap(deps.as_mut(), ACC2, &eth_lowercase, 500u128),
+            ERR_ADDRESS_BLOCKED
+        );
+
+        let first = listed(&deps, AddressList::Blocked, None);
+        assert!(!first.allowlist_enabled);
+        assert_eq!(vec![ETH_ACC1.to_string()], first.addresses);
+        let second = listed(&deps, AddressList::Blocked, Some(ETH_ACC1));
+        assert_eq!(vec![ACC1.to_string()], second.addresses);
+
+        // only an admin unblocks
+        let response = update_list(deps.as_mut(), MONITOR, AddressList::Blocked, &[], &[ACC1]);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        update_list(
+            deps.as_mut(),
+            DEFAULT_OWNER,
+            AddressList::Blocked,
+            &[],
+            &[ACC1, ETH_ACC1],
+        )
+        .unwrap();
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+    }
+
+    #[test]
+    fn success_hold_blocked_reverse_swap() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+        update_list(deps.as_mut(), MONITOR, AddressList::Blocked, &[ACC1], &[]).unwrap();
+
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        assert!(response.messages.is_empty());
+        assert_eq!("payout_held", response.events[0].ty);
+
+        let payout = Payout {
+            operation: "reverse_swap".to_string(),
+            id: 0,
+            to: addr!(ACC1),
+            denom: DEFAULT_DENUM.to_string(),
+            amount: cu128!(800u128 - DEFAULT_SWAP_FEE),
+        };
+        assert_eq!(vec![payout], held(&deps));
+        // the reverse swap went through all the same
+        expect_error!(
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0),
+            ERR_ALREADY_REVERSE_SWAPPED
+        );
+
+        let response = release(deps.as_mut(), MONITOR, "reverse_swap", 0, Some(ACC2));
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        let response =
+            release(deps.as_mut(), DEFAULT_OWNER, "reverse_swap", 0, Some(ACC2)).unwrap();
+        assert_eq!(
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ACC2.to_string(),
+                amount: coins(800u128 - DEFAULT_SWAP_FEE, DEFAULT_DENUM),
+            }),
+            response.messages[0].msg
+        );
+        assert!(held(&deps).is_empty());
+
+        let response = release(deps.as_mut(), DEFAULT_OWNER, "reverse_swap", 0, None);
+        expect_error!(response, ERR_HELD_PAYOUT_NOT_FOUND);
+    }
+
+    #[test]
+    fn success_release_elsewhere_timelocked() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        let msg = ExecuteMsg::SetTimelockDelay { blocks: 10 };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        update_list(deps.as_mut(), MONITOR, AddressList::Blocked, &[ACC1], &[]).unwrap();
+        reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+        reverse_swap(deps.as_mut(), RELAYER, 1, ACC1, ETH_ACC1, HASH, 800u128, 0).unwrap();
+
+        let response =
+            release(deps.as_mut(), DEFAULT_OWNER, "reverse_swap", 0, Some(ACC2)).unwrap();
+        assert_eq!(attr("action", "queue_operation"), response.attributes[0]);
+        assert!(response.messages.is_empty());
+        assert_eq!(2, held(&deps).len());
+
+        // releasing to the recipient itself isn't held back
+        let response =
+            release(deps.as_mut(), DEFAULT_OWNER, "reverse_swap", 1, Some(ACC1)).unwrap();
+        assert_eq!(1, response.messages.len());
+        assert_eq!(1, held(&deps).len());
+    }
+
+    #[test]
+    fn success_hold_blocked_dust_and_failed_payouts() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        let msg = ExecuteMsg::SetDustPolicy {
+            policy: DustPolicy::Hold,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+        update_list(deps.as_mut(), MONITOR, AddressList::Blocked, &[ACC1], &[]).unwrap();
+
+        // dust isn't credited to a blocked address
+        let response =
+            reverse_swap(deps.as_mut(), RELAYER, 0, ACC1, ETH_ACC1, HASH, 80u128, 0).unwrap();
+        assert_eq!("dust_held", response.events[0].ty);
+        assert_eq!("payout_held", response.events[1].ty);
+        assert!(!CLAIMABLE.has(&deps.storage, (&addr!(ACC1), DEFAULT_DENUM)));
+        assert_eq!(cu128!(80u128), held(&deps)[0].amount);
+
+        // nor is a released payout failing
+        let response = release(deps.as_mut(), DEFAULT_OWNER, "reverse_swap", 0, None).unwrap();
+        let submsg = &response.messages[0];
+        let msg = Reply {
+            id: submsg.id,
+            payload: submsg.payload.clone(),
+            gas_used: 0,
+            result: SubMsgResult::Err("blocked address".to_string()),
+        };
+        let response = reply(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!("payout_failed", response.events[0].ty);
+        assert_eq!("payout_held", response.events[1].ty);
+        assert!(!CLAIMABLE.has(&deps.storage, (&addr!(ACC1), DEFAULT_DENUM)));
+        assert_eq!(1, held(&deps).len());
+    }
+
+    #[test]
+    fn success_allowlist_mode() {
+        let mut deps = mock_deps();
+        setup(&mut deps);
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+
+        let msg = ExecuteMsg::SetAllowlistEnabled { enabled: true };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(MONITOR), &[]),
+            msg.clone(),
+        );
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(DEFAULT_OWNER), &[]),
+            msg,
+        )
+        .unwrap();
+
+        expect_error!(
+            swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128),
+            ERR_ADDRESS_BLOCKED
+        );
+        let response = update_list(deps.as_mut(), MONITOR, AddressList::Allowed, &[ACC1], &[]);
+        expect_error!(response, ERR_ACCESS_CONTROL_ONLY_ADMIN);
+        update_list(
+            deps.as_mut(),
+            DEFAULT_OWNER,
+            AddressList::Allowed,
+            &[ACC1, ETH_ACC1],
+            &[],
+        )
+        .unwrap();
+        swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128).unwrap();
+
+        // refunds to addresses not allowed are held too
+        let msg = ExecuteMsg::Refund {
+            id: 0,
+            to: addr!(ACC2),
+            amount: cu128!(500u128),
+            relay_eon: 0,
+            asset: None,
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr!(RELAYER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert!(response.messages.is_empty());
+        let held = held(&deps);
+        assert_eq!(1, held.len());
+        assert_eq!("refund", held[0].operation);
+        assert_eq!(addr!(ACC2), held[0].to);
+
+        // monitors may still narrow the allowlist down
+        update_list(deps.as_mut(), MONITOR, AddressList::Allowed, &[], &[ACC1]).unwrap();
+        expect_error!(
+            swap(deps.as_mut(), ACC1, ETH_ACC1, 500u128),
+            ERR_ADDRESS_BLOCKED
         );
     }
 }